@@ -1,5 +1,9 @@
 use bevy::prelude::*;
 
+pub use tween::*;
+
+mod tween;
+
 #[derive(Component)]
 pub struct AnimationIndices {
     pub first: usize,
@@ -9,11 +13,24 @@ pub struct AnimationIndices {
 #[derive(Component, Deref, DerefMut)]
 pub struct AnimationTimer(pub Timer);
 
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct TweenSystemSet;
+
 pub struct AnimationPlugin;
 
 impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(animate_sprite);
+        app.add_event::<TweenCompleted>()
+            .add_system(animate_sprite)
+            .add_systems(
+                (
+                    animate_tweens::<TranslationLens>,
+                    animate_tweens::<RotationLens>,
+                    animate_tweens::<ScaleLens>,
+                    animate_tweens::<SpriteColorLens>,
+                )
+                    .in_set(TweenSystemSet),
+            );
     }
 }
 
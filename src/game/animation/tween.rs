@@ -0,0 +1,162 @@
+use std::f32::consts::PI;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EaseFunction {
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicOut,
+    BackOut,
+    SineInOut,
+}
+
+impl EaseFunction {
+    pub fn apply(&self, ratio: f32) -> f32 {
+        let t = ratio.clamp(0.0, 1.0);
+
+        match self {
+            EaseFunction::Linear => t,
+            EaseFunction::QuadIn => t * t,
+            EaseFunction::QuadOut => t * (2.0 - t),
+            EaseFunction::QuadInOut => if t < 0.5 { 2.0 * t * t } else { -1.0 + (4.0 - 2.0 * t) * t },
+            EaseFunction::CubicOut => 1.0 - (1.0 - t).powi(3),
+            EaseFunction::BackOut => {
+                let overshoot = 1.70158;
+                1.0 + (overshoot + 1.0) * (t - 1.0).powi(3) + overshoot * (t - 1.0).powi(2)
+            }
+            EaseFunction::SineInOut => -((PI * t).cos() - 1.0) / 2.0,
+        }
+    }
+}
+
+pub trait TweenLens: Send + Sync + 'static {
+    type Target: Component;
+
+    fn lerp(&self, target: &mut Self::Target, ratio: f32);
+}
+
+pub struct TranslationLens {
+    pub start: Vec3,
+    pub end: Vec3,
+}
+
+impl TweenLens for TranslationLens {
+    type Target = Transform;
+
+    fn lerp(&self, target: &mut Transform, ratio: f32) {
+        target.translation = self.start.lerp(self.end, ratio);
+    }
+}
+
+pub struct RotationLens {
+    pub start: Quat,
+    pub end: Quat,
+}
+
+impl TweenLens for RotationLens {
+    type Target = Transform;
+
+    fn lerp(&self, target: &mut Transform, ratio: f32) {
+        target.rotation = self.start.slerp(self.end, ratio);
+    }
+}
+
+pub struct ScaleLens {
+    pub start: Vec3,
+    pub end: Vec3,
+}
+
+impl TweenLens for ScaleLens {
+    type Target = Transform;
+
+    fn lerp(&self, target: &mut Transform, ratio: f32) {
+        target.scale = self.start.lerp(self.end, ratio);
+    }
+}
+
+pub struct SpriteColorLens {
+    pub start: Color,
+    pub end: Color,
+}
+
+impl TweenLens for SpriteColorLens {
+    type Target = Sprite;
+
+    fn lerp(&self, target: &mut Sprite, ratio: f32) {
+        let start = Vec4::from(self.start.as_rgba_f32());
+        let end = Vec4::from(self.end.as_rgba_f32());
+        target.color = start.lerp(end, ratio).into();
+    }
+}
+
+#[derive(Component)]
+pub struct Tween<Lens: TweenLens> {
+    pub lens: Lens,
+    pub ease: EaseFunction,
+    timer: Timer,
+}
+
+impl<Lens: TweenLens> Tween<Lens> {
+    pub fn new(lens: Lens, ease: EaseFunction, seconds: f32) -> Self {
+        Self {
+            lens,
+            ease,
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+
+    fn advance(&mut self, delta: Duration) -> f32 {
+        if self.timer.duration().is_zero() {
+            return 1.0;
+        }
+
+        self.timer.tick(delta).percent()
+    }
+
+    fn finished(&self) -> bool {
+        self.timer.duration().is_zero() || self.timer.finished()
+    }
+}
+
+#[allow(dead_code)]
+pub struct TweenCompleted {
+    pub entity: Entity,
+}
+
+pub(super) fn animate_tweens<Lens: TweenLens>(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut tween_query: Query<(Entity, &mut Tween<Lens>, &mut Lens::Target)>,
+    mut tween_completed_events: EventWriter<TweenCompleted>,
+) {
+    for (entity, mut tween, mut target) in tween_query.iter_mut() {
+        let ratio = tween.advance(time.delta());
+        tween.lens.lerp(&mut target, tween.ease.apply(ratio));
+
+        if ratio >= 1.0 {
+            // a tween inserted later this frame replaces this one, so only remove it if still finished
+            commands.add(move |world: &mut World| {
+                if world.get::<Tween<Lens>>(entity).is_some_and(|tween| tween.finished()) {
+                    world.entity_mut(entity).remove::<Tween<Lens>>();
+                }
+            });
+            tween_completed_events.send(TweenCompleted { entity });
+        }
+    }
+}
+
+pub type WithAnyTween = Or<(
+    With<Tween<TranslationLens>>,
+    With<Tween<RotationLens>>,
+    With<Tween<ScaleLens>>,
+    With<Tween<SpriteColorLens>>,
+)>;
+
+pub fn tweens_finished(tween_query: Query<(), WithAnyTween>) -> bool {
+    tween_query.is_empty()
+}
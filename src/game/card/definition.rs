@@ -0,0 +1,135 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    ecs::system::SystemParam,
+    prelude::*,
+    reflect::TypeUuid,
+    utils::BoxedFuture,
+};
+use serde::Deserialize;
+
+pub const CARD_LIBRARY_PATH: &str = "cards/base.cards.ron";
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CardType {
+    Attack,
+    Defense,
+    Utility,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CardEffect {
+    Damage(u8),
+    Heal(u8),
+    Protection(u8),
+    Memory(u8),
+    Draw(u8),
+    Discard(u8),
+    Destroy,
+}
+
+impl CardEffect {
+    pub fn description(&self) -> String {
+        match self {
+            CardEffect::Damage(amount) => format!("Deal {} damage", amount),
+            CardEffect::Heal(amount) => format!("Restore {} integrity", amount),
+            CardEffect::Protection(amount) => format!("Gain {} protection", amount),
+            CardEffect::Memory(amount) => format!("Gain {} memory", amount),
+            CardEffect::Draw(count) => format!("Draw {} cards", count),
+            CardEffect::Discard(count) => format!("Opponent discards {}", count),
+            CardEffect::Destroy => "Destroy a card in the opponent's hand".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct CardDefinition {
+    pub id: String,
+    pub name: String,
+    pub memory_cost: u8,
+    pub art: String,
+    pub card_type: CardType,
+    pub effects: Vec<CardEffect>,
+    #[serde(default)]
+    pub side_effects: Vec<CardEffect>,
+}
+
+impl CardDefinition {
+    pub fn rules_text(&self) -> String {
+        self.effects.iter()
+            .map(|effect| effect.description())
+            .chain(self.side_effects.iter().map(|effect| format!("Then: {}", effect.description())))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[derive(Deserialize, TypeUuid, Debug)]
+#[uuid = "6b1f7a52-3c9e-4d0b-9a8e-2f4c1d7e5b90"]
+pub struct CardLibrary {
+    pub cards: Vec<CardDefinition>,
+    pub starter_deck: Vec<String>,
+}
+
+impl CardLibrary {
+    pub fn get(&self, id: &str) -> Option<&CardDefinition> {
+        self.cards.iter().find(|definition| definition.id == id)
+    }
+}
+
+#[derive(Resource)]
+pub struct CardLibraryHandle(pub Handle<CardLibrary>);
+
+#[derive(SystemParam)]
+pub struct CardDefinitions<'w> {
+    handle: Option<Res<'w, CardLibraryHandle>>,
+    libraries: Res<'w, Assets<CardLibrary>>,
+}
+
+impl<'w> CardDefinitions<'w> {
+    pub fn library(&self) -> Option<&CardLibrary> {
+        self.handle.as_ref().and_then(|handle| self.libraries.get(&handle.0))
+    }
+
+    pub fn get(&self, id: &str) -> Option<&CardDefinition> {
+        self.library().and_then(|library| library.get(id))
+    }
+}
+
+#[derive(Default)]
+pub struct CardLibraryLoader;
+
+impl AssetLoader for CardLibraryLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let library = ron::de::from_bytes::<CardLibrary>(bytes)?;
+            info!("card library loaded {:?} cards", library.cards.len());
+            load_context.set_default_asset(LoadedAsset::new(library));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["cards.ron"]
+    }
+}
+
+pub(super) fn load_card_library(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.insert_resource(CardLibraryHandle(asset_server.load(CARD_LIBRARY_PATH)));
+}
+
+pub(super) fn log_card_library_changes(
+    mut asset_events: EventReader<AssetEvent<CardLibrary>>,
+) {
+    for event in asset_events.iter() {
+        if let AssetEvent::Modified { .. } = event {
+            info!("card library reloaded");
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use bevy::{
+    prelude::*,
+    sprite::Anchor,
+    text::Text2dBounds,
+};
+
+use crate::game::hud::{BitmapNumberConfig, BitmapNumberValue};
+use crate::game::screen::SCREEN_FONT_PATH;
+
+use super::{CARD_SIZE, CardDefinition, CardType};
+
+pub const CARD_FRAME_PATH: &str = "textures/cards/frame.png";
+pub const CARD_ICONS_PATH: &str = "textures/cards/icons.png";
+pub const CARD_COST_NUMBERS_PATH: &str = "textures/UI/MB/UI_MB_Numbers.png";
+const CARD_ART_SIZE: Vec2 = Vec2::new(84.0, 60.0);
+const CARD_ICON_SIZE: Vec2 = Vec2::new(16.0, 16.0);
+const CARD_DIGIT_SIZE: Vec2 = Vec2::new(12.0, 23.0);
+const CARD_TEXT_COLOR: Color = Color::WHITE;
+
+#[derive(Resource)]
+pub struct CardFaceAssets {
+    pub frame: Handle<Image>,
+    pub icons: Handle<TextureAtlas>,
+    pub digits: Handle<TextureAtlas>,
+    pub font: Handle<Font>,
+}
+
+#[derive(Component)]
+pub struct CardFace;
+
+#[derive(Component)]
+pub struct CardCostValue(pub u8);
+
+impl BitmapNumberValue for CardCostValue {
+    fn get_value(&self) -> u8 {
+        self.0
+    }
+
+    fn set_value(&mut self, value: u8) {
+        self.0 = value;
+    }
+}
+
+#[derive(Component)]
+pub struct CardCostDigit;
+
+pub fn card_type_color(card_type: CardType) -> Color {
+    match card_type {
+        CardType::Attack => Color::rgb(0.45, 0.16, 0.16),
+        CardType::Defense => Color::rgb(0.16, 0.24, 0.45),
+        CardType::Utility => Color::rgb(0.16, 0.4, 0.22),
+    }
+}
+
+fn card_type_icon_index(card_type: CardType) -> usize {
+    match card_type {
+        CardType::Attack => 0,
+        CardType::Defense => 1,
+        CardType::Utility => 2,
+    }
+}
+
+pub(super) fn load_card_face_assets(
+    mut commands: Commands,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    asset_server: Res<AssetServer>,
+) {
+    let icons = TextureAtlas::from_grid(
+        asset_server.load(CARD_ICONS_PATH),
+        CARD_ICON_SIZE,
+        3,
+        1,
+        None,
+        None,
+    );
+    let digits = TextureAtlas::from_grid(
+        asset_server.load(CARD_COST_NUMBERS_PATH),
+        CARD_DIGIT_SIZE,
+        10,
+        1,
+        None,
+        None,
+    );
+
+    commands.insert_resource(CardFaceAssets {
+        frame: asset_server.load(CARD_FRAME_PATH),
+        icons: texture_atlases.add(icons),
+        digits: texture_atlases.add(digits),
+        font: asset_server.load(SCREEN_FONT_PATH),
+    });
+}
+
+fn card_text(value: &str, font: &Handle<Font>, font_size: f32, bounds: Vec2, translation: Vec3) -> Text2dBundle {
+    Text2dBundle {
+        text: Text::from_section(
+            value,
+            TextStyle {
+                font: font.clone(),
+                font_size,
+                color: CARD_TEXT_COLOR,
+            },
+        ).with_alignment(TextAlignment::Center),
+        text_anchor: Anchor::TopCenter,
+        text_2d_bounds: Text2dBounds { size: bounds },
+        transform: Transform::from_translation(translation),
+        ..default()
+    }
+}
+
+pub fn spawn_card_face(
+    definition: &CardDefinition,
+    face_assets: &CardFaceAssets,
+    asset_server: &AssetServer,
+    parent: &mut ChildBuilder,
+) {
+    parent.spawn((
+        CardFace {},
+        SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(CARD_ART_SIZE),
+                ..default()
+            },
+            texture: asset_server.load(definition.art.as_str()),
+            transform: Transform::from_xyz(0.0, 22.0, 0.001),
+            ..default()
+        },
+    ));
+
+    parent.spawn((
+        CardFace {},
+        SpriteBundle {
+            sprite: Sprite {
+                custom_size: Some(CARD_SIZE),
+                ..default()
+            },
+            texture: face_assets.frame.clone(),
+            transform: Transform::from_xyz(0.0, 0.0, 0.002),
+            ..default()
+        },
+    ));
+
+    parent.spawn((
+        CardFace {},
+        CardCostValue(definition.memory_cost),
+        BitmapNumberConfig {
+            tile_size: CARD_DIGIT_SIZE,
+            columns: 10,
+            row: 0,
+            font_spacing: 1.0,
+        },
+        SpatialBundle {
+            transform: Transform::from_xyz(-30.0, 60.0, 0.003).with_scale(Vec3::splat(0.75)),
+            ..default()
+        },
+    )).with_children(|parent| {
+        for _ in 0..2 {
+            parent.spawn((
+                CardCostDigit {},
+                SpriteSheetBundle {
+                    texture_atlas: face_assets.digits.clone(),
+                    ..default()
+                },
+            ));
+        }
+    });
+
+    parent.spawn((
+        CardFace {},
+        SpriteSheetBundle {
+            sprite: TextureAtlasSprite::new(card_type_icon_index(definition.card_type)),
+            texture_atlas: face_assets.icons.clone(),
+            transform: Transform::from_xyz(36.0, 60.0, 0.003),
+            ..default()
+        },
+    ));
+
+    parent.spawn((
+        CardFace {},
+        card_text(&definition.name, &face_assets.font, 11.0, Vec2::new(84.0, 14.0), Vec3::new(0.0, -10.0, 0.003)),
+    ));
+
+    parent.spawn((
+        CardFace {},
+        card_text(&definition.rules_text(), &face_assets.font, 9.0, Vec2::new(80.0, 42.0), Vec3::new(0.0, -28.0, 0.003)),
+    ));
+}
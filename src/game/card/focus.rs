@@ -0,0 +1,227 @@
+use bevy::prelude::*;
+
+use crate::game::combat::{Card, PlayerNumber};
+
+use super::{
+    CardArea, CardDragCancelEvent, CardDragEvent, CardDragStartEvent, CardDropEvent, CardHoverEvent, CardPressEvent,
+    CardZone, CardsOrder, Picking, layout_transform,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FocusAction {
+    Left,
+    Right,
+    Up,
+    Down,
+    Confirm,
+    Cancel,
+}
+
+#[derive(Resource, Default)]
+pub struct CardFocus {
+    pub focused: Option<Entity>,
+    pub held: Option<Entity>,
+    pub target: Option<(Entity, usize)>,
+}
+
+pub(super) fn send_keyboard_focus_actions(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut focus_actions: EventWriter<FocusAction>,
+) {
+    let bindings = [
+        (KeyCode::Left, FocusAction::Left),
+        (KeyCode::Right, FocusAction::Right),
+        (KeyCode::Up, FocusAction::Up),
+        (KeyCode::Down, FocusAction::Down),
+        (KeyCode::Return, FocusAction::Confirm),
+        (KeyCode::Escape, FocusAction::Cancel),
+    ];
+
+    for (key_code, action) in bindings {
+        if keyboard_input.just_pressed(key_code) {
+            focus_actions.send(action);
+        }
+    }
+}
+
+pub(super) fn send_gamepad_focus_actions(
+    gamepads: Res<Gamepads>,
+    gamepad_input: Res<Input<GamepadButton>>,
+    mut focus_actions: EventWriter<FocusAction>,
+) {
+    let bindings = [
+        (GamepadButtonType::DPadLeft, FocusAction::Left),
+        (GamepadButtonType::DPadRight, FocusAction::Right),
+        (GamepadButtonType::DPadUp, FocusAction::Up),
+        (GamepadButtonType::DPadDown, FocusAction::Down),
+        (GamepadButtonType::South, FocusAction::Confirm),
+        (GamepadButtonType::East, FocusAction::Cancel),
+    ];
+
+    for gamepad in gamepads.iter() {
+        for (button_type, action) in bindings {
+            if gamepad_input.just_pressed(GamepadButton::new(gamepad, button_type)) {
+                focus_actions.send(action);
+            }
+        }
+    }
+}
+
+fn neighbour_area(areas: &[(Entity, f32)], area: Entity, step: isize) -> Option<Entity> {
+    let index = areas.iter().position(|(entity, _)| *entity == area)? as isize + step;
+    usize::try_from(index).ok().and_then(|index| areas.get(index)).map(|(entity, _)| *entity)
+}
+
+fn own_cards(cards_order: &CardsOrder, card_query: &Query<&Card>) -> Vec<Entity> {
+    cards_order.0.iter()
+        .copied()
+        .filter(|entity| card_query.get(*entity).is_ok_and(|card| card.owner == PlayerNumber::One))
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn navigate_card_focus(
+    mut focus: ResMut<CardFocus>,
+    mut picking: ResMut<Picking>,
+    mut focus_actions: EventReader<FocusAction>,
+    mut press_events: EventReader<CardPressEvent>,
+    area_query: Query<(Entity, &CardZone, &CardArea, &GlobalTransform, &CardsOrder)>,
+    parent_query: Query<&Parent>,
+    card_query: Query<&Card>,
+    mut hover_events: EventWriter<CardHoverEvent>,
+    mut drag_start_events: EventWriter<CardDragStartEvent>,
+    mut drag_events: EventWriter<CardDragEvent>,
+    mut drop_events: EventWriter<CardDropEvent>,
+    mut drag_cancel_events: EventWriter<CardDragCancelEvent>,
+) {
+    if focus.focused.is_some_and(|entity| parent_query.get(entity).is_err()) {
+        *focus = CardFocus::default();
+    }
+
+    // pressing a card with the pointer moves the focus along with it
+    if let Some(event) = press_events.iter().last() {
+        if focus.held.is_none() {
+            focus.focused = Some(event.entity);
+        }
+    }
+
+    let mut areas: Vec<(Entity, f32)> = area_query.iter()
+        .map(|(entity, _, _, global_transform, _)| (entity, global_transform.translation().y))
+        .collect();
+    areas.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    for action in focus_actions.iter() {
+        match (focus.held, *action) {
+            (None, FocusAction::Left | FocusAction::Right | FocusAction::Up | FocusAction::Down) => {
+                let current = focus.focused
+                    .and_then(|entity| parent_query.get(entity).ok())
+                    .and_then(|parent| area_query.get(parent.get()).ok())
+                    .and_then(|(area, .., cards_order)| {
+                        own_cards(cards_order, &card_query).iter()
+                            .position(|entity| Some(*entity) == focus.focused)
+                            .map(|index| (area, index))
+                    });
+
+                let next = match (current, *action) {
+                    (None, _) => areas.iter()
+                        .find_map(|(area, _)| area_query.get(*area).ok()
+                            .filter(|(_, zone, ..)| **zone == CardZone::Hand)
+                            .and_then(|(.., cards_order)| own_cards(cards_order, &card_query).first().copied())),
+                    (Some((area, index)), FocusAction::Left | FocusAction::Right) => {
+                        let index = if *action == FocusAction::Left { index.saturating_sub(1) } else { index + 1 };
+                        area_query.get(area).ok()
+                            .map(|(.., cards_order)| own_cards(cards_order, &card_query))
+                            .and_then(|cards| cards.get(index.min(cards.len() - 1)).copied())
+                    }
+                    (Some((area, index)), _) => {
+                        let step = if *action == FocusAction::Up { 1 } else { -1 };
+                        let mut next_area = neighbour_area(&areas, area, step);
+
+                        while let Some(candidate) = next_area {
+                            if area_query.get(candidate).is_ok_and(|(.., cards_order)| !own_cards(cards_order, &card_query).is_empty()) {
+                                break;
+                            }
+                            next_area = neighbour_area(&areas, candidate, step);
+                        }
+
+                        next_area
+                            .and_then(|area| area_query.get(area).ok())
+                            .map(|(.., cards_order)| own_cards(cards_order, &card_query))
+                            .and_then(|cards| cards.get(index.min(cards.len() - 1)).copied())
+                    }
+                };
+
+                if next.is_some() && next != focus.focused {
+                    focus.focused = next;
+                    hover_events.send(CardHoverEvent { entity: next });
+                }
+            }
+            (None, FocusAction::Confirm) => {
+                let Some(entity) = focus.focused else {
+                    continue;
+                };
+
+                if picking.dragged.is_some()
+                    || !card_query.get(entity).is_ok_and(|card| card.owner == PlayerNumber::One) {
+                    continue;
+                }
+
+                let Some((area, .., cards_order)) = parent_query.get(entity).ok()
+                    .and_then(|parent| area_query.get(parent.get()).ok()) else {
+                    continue;
+                };
+
+                focus.held = Some(entity);
+                focus.target = cards_order.0.iter().position(|other| *other == entity).map(|index| (area, index));
+                picking.dragged = Some(entity);
+                hover_events.send(CardHoverEvent { entity: None });
+                drag_start_events.send(CardDragStartEvent { entity });
+            }
+            (None, FocusAction::Cancel) => {
+                focus.focused = None;
+                hover_events.send(CardHoverEvent { entity: None });
+            }
+            (Some(entity), FocusAction::Left | FocusAction::Right | FocusAction::Up | FocusAction::Down) => {
+                let Some((area, index)) = focus.target else {
+                    continue;
+                };
+
+                let target_area = match *action {
+                    FocusAction::Up => neighbour_area(&areas, area, 1).unwrap_or(area),
+                    FocusAction::Down => neighbour_area(&areas, area, -1).unwrap_or(area),
+                    _ => area,
+                };
+
+                let Ok((_, zone, card_area, global_transform, cards_order)) = area_query.get(target_area) else {
+                    continue;
+                };
+
+                let number_of_cards = if cards_order.0.contains(&entity) { cards_order.0.len() } else { cards_order.0.len() + 1 };
+                let index = match *action {
+                    FocusAction::Left => index.saturating_sub(1),
+                    FocusAction::Right => index + 1,
+                    _ => index,
+                }.min(number_of_cards - 1);
+
+                focus.target = Some((target_area, index));
+                let slot = layout_transform(*zone, card_area, number_of_cards, index).translation;
+                drag_events.send(CardDragEvent {
+                    entity,
+                    position: global_transform.translation().truncate() + slot.truncate(),
+                });
+            }
+            (Some(entity), FocusAction::Confirm) => {
+                focus.held = None;
+                focus.target = None;
+                picking.dragged = None;
+                drop_events.send(CardDropEvent { entity, position: picking.position });
+            }
+            (Some(entity), FocusAction::Cancel) => {
+                focus.held = None;
+                focus.target = None;
+                picking.dragged = None;
+                drag_cancel_events.send(CardDragCancelEvent { entity });
+            }
+        }
+    }
+}
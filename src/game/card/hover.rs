@@ -0,0 +1,153 @@
+use bevy::prelude::*;
+
+use crate::game::MatchEntity;
+use crate::game::animation::{EaseFunction, ScaleLens, TranslationLens, Tween};
+use crate::game::combat::Card;
+use crate::game::screen::SCREEN_FONT_PATH;
+
+use super::{CARD_MOVE_SECONDS, CardBeingDragged, CardDefinitions, CardHoverEvent, CardsOrder};
+
+pub const CARD_HOVER_SCALE: f32 = 1.5;
+pub const CARD_HOVER_LIFT: f32 = 24.0;
+pub const CARD_HOVER_Z: f32 = 10.0;
+const CARD_DETAIL_BACKGROUND_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.85);
+
+#[derive(Component)]
+pub struct CardHovered {
+    pub rest_translation: Vec3,
+}
+
+#[derive(Component)]
+pub struct CardDetailPanel;
+
+#[derive(Component)]
+pub struct CardDetailText;
+
+pub(super) fn spawn_card_detail_panel(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.spawn((
+        MatchEntity,
+        CardDetailPanel {},
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    left: Val::Px(16.0),
+                    top: Val::Percent(40.0),
+                    ..default()
+                },
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            background_color: CARD_DETAIL_BACKGROUND_COLOR.into(),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+    )).with_children(|parent| {
+        parent.spawn((
+            CardDetailText {},
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font: asset_server.load(SCREEN_FONT_PATH),
+                    font_size: 18.0,
+                    color: Color::WHITE,
+                },
+            ),
+        ));
+    });
+}
+
+#[allow(clippy::type_complexity)]
+pub(super) fn hover_cards(
+    mut commands: Commands,
+    mut hover_events: EventReader<CardHoverEvent>,
+    card_query: Query<(Entity, &Transform, Option<&Tween<TranslationLens>>), (With<Card>, Without<CardBeingDragged>)>,
+    hovered_query: Query<(Entity, &Transform, &Parent), With<CardHovered>>,
+    mut area_query: Query<&mut CardsOrder>,
+) {
+    let Some(hovered) = hover_events.iter().last().map(|event| event.entity) else {
+        return;
+    };
+
+    for (entity, transform, parent) in hovered_query.iter() {
+        if Some(entity) == hovered {
+            continue;
+        }
+
+        commands.entity(entity)
+            .remove::<CardHovered>()
+            .insert(Tween::new(
+                ScaleLens { start: transform.scale, end: Vec3::ONE },
+                EaseFunction::QuadOut,
+                CARD_MOVE_SECONDS,
+            ));
+
+        if let Ok(mut cards_order) = area_query.get_mut(parent.get()) {
+            cards_order.set_changed();
+        }
+    }
+
+    let Some((entity, transform, tween)) = hovered.and_then(|entity| card_query.get(entity).ok()) else {
+        return;
+    };
+
+    if hovered_query.contains(entity) {
+        return;
+    }
+
+    let rest_translation = tween.map(|tween| tween.lens.end).unwrap_or(transform.translation);
+    commands.entity(entity).insert((
+        CardHovered { rest_translation },
+        Tween::new(
+            ScaleLens { start: transform.scale, end: Vec3::splat(CARD_HOVER_SCALE) },
+            EaseFunction::BackOut,
+            CARD_MOVE_SECONDS,
+        ),
+        Tween::new(
+            TranslationLens {
+                start: transform.translation,
+                end: rest_translation + Vec3::new(0.0, CARD_HOVER_LIFT, CARD_HOVER_Z),
+            },
+            EaseFunction::QuadOut,
+            CARD_MOVE_SECONDS,
+        ),
+    ));
+}
+
+pub(super) fn update_card_detail_panel(
+    card_definitions: CardDefinitions,
+    hovered_query: Query<&Card, With<CardHovered>>,
+    mut panel_query: Query<&mut Visibility, With<CardDetailPanel>>,
+    mut text_query: Query<&mut Text, With<CardDetailText>>,
+) {
+    let detail = hovered_query.iter().next().and_then(|card| {
+        card_definitions.get(&card.id).map(|definition| {
+            format!(
+                "{}\nMemory cost: {}\n{}\nOwner: {:?}",
+                definition.name,
+                definition.memory_cost,
+                definition.rules_text(),
+                card.owner,
+            )
+        })
+    });
+
+    for mut visibility in panel_query.iter_mut() {
+        let target = if detail.is_some() { Visibility::Inherited } else { Visibility::Hidden };
+
+        if *visibility != target {
+            *visibility = target;
+        }
+    }
+
+    if let Some(detail) = detail {
+        for mut text in text_query.iter_mut() {
+            if text.sections[0].value != detail {
+                text.sections[0].value = detail.clone();
+            }
+        }
+    }
+}
@@ -1,19 +1,43 @@
 use bevy::{
-    input::{
-        ButtonState,
-        mouse::MouseButtonInput,
-    },
     prelude::*,
     sprite::collide_aabb::collide,
-    window::PrimaryWindow,
 };
-use rand::prelude::*;
+
+use crate::game::{GameState, MatchEntity};
+use crate::game::animation::{EaseFunction, RotationLens, TranslationLens, Tween, TweenSystemSet};
+use crate::game::hud::update_bitmap_number_digits;
+use crate::game::layout::ScreenAnchor;
+use crate::game::combat::{
+    BOARD_LIMIT, Card, CardDrawnEvent, CardPlayedEvent, CardRemovedEvent, PLAY_HAND_LIMIT, PlayerNumber,
+};
+
+pub use definition::*;
+pub use face::*;
+pub use focus::*;
+pub use hover::*;
+pub use picking::*;
+pub use rules::*;
+
+mod definition;
+mod face;
+mod focus;
+mod hover;
+mod picking;
+mod rules;
 
 pub const CARD_SIZE: Vec2 = Vec2::new(96.0, 144.0);
 pub const CARD_MARGIN: f32 = 16.0;
 pub const CARD_PADDING: f32 = 1.0;
 pub const BATTLEFIELD_NUMBER_OF_CARDS: usize = 7;
 pub const PLAYER_HAND_NUMBER_OF_CARDS: usize = 5;
+pub const CARD_REJECTED_COLOR: Color = Color::RED;
+pub const CARD_REJECTED_SECONDS: f32 = 0.3;
+pub const CARD_MOVE_SECONDS: f32 = 0.2;
+pub const CARD_AREA_COLOR: Color = Color::WHITE;
+pub const CARD_AREA_HIGHLIGHT_COLOR: Color = Color::rgb(0.6, 1.0, 0.6);
+pub const CARD_AREA_DIMMED_COLOR: Color = Color::rgb(0.35, 0.35, 0.35);
+pub const HAND_FAN_ANGLE: f32 = 0.25;
+pub const HAND_FAN_DROP: f32 = 24.0;
 
 
 fn area_with(number_of_cards: usize) -> f32 {
@@ -24,16 +48,106 @@ fn area_with(number_of_cards: usize) -> f32 {
 fn card_translation(number_of_cards: usize, position: usize) -> Vec3 {
     let index = position as f32;
     let width = area_with(number_of_cards);
-    let x = -width / 2.0 + CARD_SIZE.x / 2.0 + index as f32 * (CARD_SIZE.x + CARD_PADDING);
-    info!("card_translation x: {:?}", x);
+    let x = -width / 2.0 + CARD_SIZE.x / 2.0 + index * (CARD_SIZE.x + CARD_PADDING);
     Vec3::new(x, 0.0, 1.0)
 }
 
+fn hand_card_spread(number_of_cards: usize) -> f32 {
+    if number_of_cards > PLAYER_HAND_NUMBER_OF_CARDS {
+        (area_with(PLAYER_HAND_NUMBER_OF_CARDS) - CARD_SIZE.x) / (number_of_cards - 1) as f32
+    } else {
+        CARD_SIZE.x + CARD_PADDING
+    }
+}
+
+fn hand_card_transform(number_of_cards: usize, position: usize) -> Transform {
+    let fanned = number_of_cards > PLAYER_HAND_NUMBER_OF_CARDS;
+    let spread = hand_card_spread(number_of_cards);
+    let half = (number_of_cards as f32 - 1.0) / 2.0;
+    let offset = position as f32 - half;
+    let mut translation = Vec3::new(offset * spread, 0.0, 1.0 + position as f32 * 0.01);
+
+    if !fanned {
+        return Transform::from_translation(translation);
+    }
+
+    let ratio = offset / half;
+    translation.y = -ratio * ratio * HAND_FAN_DROP;
+    Transform::from_translation(translation).with_rotation(Quat::from_rotation_z(-ratio * HAND_FAN_ANGLE))
+}
+
+fn drop_position(zone: CardZone, card_area: &CardArea, len: usize, card_translation_x: f32) -> usize {
+    let position = match zone {
+        CardZone::Battlefield(_) => {
+            let relative_hover_x = card_translation_x + area_with(card_area.number_of_cards) / 2.0 - CARD_SIZE.x / 2.0;
+
+            (relative_hover_x / (CARD_SIZE.x + CARD_PADDING)).round()
+        }
+        CardZone::Hand => {
+            let half = (len as f32 - 1.0) / 2.0;
+
+            (card_translation_x / hand_card_spread(len) + half).round()
+        }
+    };
+
+    (position.max(0.0) as usize).min(len - 1)
+}
+
+fn insertion_position(
+    zone: CardZone,
+    card_area: &CardArea,
+    area_global_transform: &GlobalTransform,
+    cards_order: &CardsOrder,
+    card_entity: Entity,
+    card_position: Vec3,
+) -> usize {
+    let card_translation_x = card_position.x - area_global_transform.translation().x;
+
+    if cards_order.0.contains(&card_entity) {
+        drop_position(zone, card_area, cards_order.0.len(), card_translation_x)
+    } else {
+        drop_position(zone, card_area, cards_order.0.len() + 1, card_translation_x)
+    }
+}
+
+fn area_distance(sprite: &Sprite, transform: &Transform, global_transform: &GlobalTransform, card_position: Vec3) -> Option<f32> {
+    let area_size = sprite.custom_size.unwrap() * transform.scale.truncate();
+    collide(global_transform.translation(), area_size, card_position, CARD_SIZE)
+        .map(|_| global_transform.translation().distance(card_position))
+}
+
+fn layout_transform(zone: CardZone, card_area: &CardArea, number_of_cards: usize, position: usize) -> Transform {
+    match zone {
+        CardZone::Battlefield(_) => Transform::from_translation(card_translation(card_area.number_of_cards, position)),
+        CardZone::Hand => hand_card_transform(number_of_cards, position),
+    }
+}
+
+fn tween_card(commands: &mut Commands, card_entity: Entity, card_transform: &Transform, tween: Option<&Tween<TranslationLens>>, target: Transform) {
+    let end = tween.map(|tween| tween.lens.end).unwrap_or(card_transform.translation);
+
+    if end == target.translation {
+        return;
+    }
+
+    commands.entity(card_entity).insert((
+        Tween::new(
+            TranslationLens { start: card_transform.translation, end: target.translation },
+            EaseFunction::QuadOut,
+            CARD_MOVE_SECONDS,
+        ),
+        Tween::new(
+            RotationLens { start: card_transform.rotation, end: target.rotation },
+            EaseFunction::QuadOut,
+            CARD_MOVE_SECONDS,
+        ),
+    ));
+}
 
 fn build_sprite_bundle(width: f32, translation_y: f32) -> SpriteBundle {
     SpriteBundle {
         sprite: Sprite {
-            color: Color::WHITE,
+            color: CARD_AREA_COLOR,
             custom_size: Some(Vec2::new(width, CARD_SIZE.y)),
             ..default()
         },
@@ -42,68 +156,125 @@ fn build_sprite_bundle(width: f32, translation_y: f32) -> SpriteBundle {
     }
 }
 
-fn mouse_translation(position: Vec2, window: &Window, z: f32) -> Vec3 {
-    Vec3::new(
-        position.x - window.width() / 2.0,
-        position.y - window.height() / 2.0,
-        z)
-}
-
-#[derive(Resource)]
-struct MousePosition(Vec2);
-
-impl Default for MousePosition {
-    fn default() -> Self {
-        Self(Vec2::ZERO)
-    }
-}
 
 #[derive(Component)]
 pub struct CardArea {
     number_of_cards: usize,
+    capacity: usize,
 }
 
-#[derive(Component)]
-pub struct Battlefield;
+impl CardArea {
+    pub fn new(number_of_cards: usize, capacity: usize) -> Self {
+        Self { number_of_cards, capacity }
+    }
+
+    pub fn accepts(&self, cards_order: &CardsOrder) -> bool {
+        cards_order.0.len() < self.capacity
+    }
+}
 
 #[derive(Component)]
-pub struct PlayerHand;
+pub struct Battlefield(pub PlayerNumber);
 
 #[derive(Component)]
-pub struct Card;
+pub struct PlayerHand;
 
 #[derive(Component)]
 pub struct CardBeingDragged {
     original_translation: Vec3,
 }
 
+#[derive(Component)]
+pub struct CardRejected {
+    timer: Timer,
+    original_color: Color,
+}
+
 #[derive(Component, Reflect)]
 pub struct CardPlaced;
 
 #[derive(Component, Reflect)]
-pub struct CardsOrder(Vec<Entity>);
+pub struct CardsOrder(pub Vec<Entity>);
 
 pub struct CardPlugin;
 
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct CardInputSystemSet;
+
 impl Plugin for CardPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<MousePosition>()
+        app.init_resource::<Picking>()
+            .add_event::<PointerEvent>()
+            .add_event::<CardHoverEvent>()
+            .add_event::<CardPressEvent>()
+            .add_event::<CardDragStartEvent>()
+            .add_event::<CardDragEvent>()
+            .add_event::<CardDragCancelEvent>()
+            .add_event::<CardDropEvent>()
+            .init_resource::<CardFocus>()
+            .add_event::<FocusAction>()
             .register_type::<CardsOrder>()
-            .add_startup_system(spawn_line_middle_screen)
-            .add_startup_system(spawn_battlefield)
-            .add_startup_system(spawn_player_hand)
-            .add_system(update_mouse_position)
-            .add_system(mouse_pressed.after(update_mouse_position))
-            .add_system(move_card.after(mouse_pressed))
-            .add_system(mouse_released.after(move_card))
-            .add_system(reorder_cards.after(mouse_released));
+            .add_asset::<CardLibrary>()
+            .init_asset_loader::<CardLibraryLoader>()
+            .add_startup_system(load_card_library)
+            .add_system(spawn_line_middle_screen.in_schedule(OnEnter(GameState::InMatch)))
+            .add_system(spawn_battlefield.in_schedule(OnEnter(GameState::InMatch)))
+            .add_system(spawn_player_hand.in_schedule(OnEnter(GameState::InMatch)))
+            .add_system(log_card_library_changes)
+            .add_system(spawn_drawn_cards)
+            .add_system(spawn_played_cards.after(TweenSystemSet))
+            .add_system(despawn_removed_cards)
+            .add_system(pick_cards
+                .after(CardInputSystemSet)
+                .after(TweenSystemSet)
+                .run_if(in_state(GameState::InMatch)))
+            .add_system(navigate_card_focus
+                .after(CardInputSystemSet)
+                .after(pick_cards)
+                .run_if(in_state(GameState::InMatch)))
+            .add_system(start_card_drag.after(navigate_card_focus).run_if(in_state(GameState::InMatch)))
+            .add_system(apply_system_buffers.after(start_card_drag).before(move_card))
+            .add_system(move_card.after(start_card_drag).run_if(in_state(GameState::InMatch)))
+            .add_system(hover_cards.after(move_card).run_if(in_state(GameState::InMatch)))
+            .add_system(preview_card_drop.after(move_card).run_if(in_state(GameState::InMatch)))
+            .add_system(drop_cards.after(preview_card_drop).run_if(in_state(GameState::InMatch)))
+            .add_system(cancel_card_drags.after(preview_card_drop).run_if(in_state(GameState::InMatch)))
+            .add_system(reorder_cards.after(drop_cards).after(TweenSystemSet))
+            .add_system(reorder_hand_cards.after(drop_cards).after(TweenSystemSet))
+            .add_system(fade_card_rejected);
+    }
+}
+
+pub struct CardRenderPlugin;
+
+impl Plugin for CardRenderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_startup_system(load_card_face_assets)
+            .add_system(spawn_card_detail_panel.in_schedule(OnEnter(GameState::InMatch)))
+            .add_system(update_card_detail_panel.after(hover_cards))
+            .add_system(update_bitmap_number_digits::<CardCostValue, CardCostDigit>);
+    }
+}
+
+pub struct CardInputPlugin;
+
+impl Plugin for CardInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems((
+            send_mouse_pointer_events,
+            send_touch_pointer_events,
+            send_keyboard_focus_actions,
+            send_gamepad_focus_actions,
+        ).in_set(CardInputSystemSet));
     }
 }
 
 fn spawn_line_middle_screen(mut commands: Commands) {
     let width = area_with(BATTLEFIELD_NUMBER_OF_CARDS);
 
-    commands.spawn(
+    commands.spawn((
+        MatchEntity,
+        ScreenAnchor::new(ScreenAnchor::CENTER, Vec2::ZERO),
         SpriteBundle {
             sprite: Sprite {
                 color: Color::WHITE,
@@ -111,214 +282,420 @@ fn spawn_line_middle_screen(mut commands: Commands) {
                 ..default()
             },
             ..default()
-        });
+        },
+    ));
+}
+
+fn battlefield_translation_y(owner: PlayerNumber) -> f32 {
+    match owner {
+        PlayerNumber::One => -CARD_SIZE.y / 2.0 - CARD_MARGIN,
+        PlayerNumber::Two => CARD_SIZE.y / 2.0 + CARD_MARGIN,
+    }
 }
 
 fn spawn_battlefield(mut commands: Commands) {
     let width = area_with(BATTLEFIELD_NUMBER_OF_CARDS);
-    let translation_y = -CARD_SIZE.y / 2.0 - CARD_MARGIN;
+
+    for owner in [PlayerNumber::One, PlayerNumber::Two] {
+        commands.spawn((
+            MatchEntity,
+            CardArea::new(BATTLEFIELD_NUMBER_OF_CARDS, BOARD_LIMIT as usize),
+            CardsOrder(vec![]),
+            CardZone::Battlefield(owner),
+            Battlefield(owner),
+            ScreenAnchor::new(ScreenAnchor::CENTER, Vec2::new(0.0, battlefield_translation_y(owner))),
+            build_sprite_bundle(width, battlefield_translation_y(owner))
+        ));
+    }
+}
+
+fn spawn_player_hand(mut commands: Commands) {
+    let width = area_with(PLAYER_HAND_NUMBER_OF_CARDS);
+    let translation_y = (CARD_SIZE.y / 2.0) + CARD_MARGIN;
 
     commands.spawn((
-        CardArea { number_of_cards: BATTLEFIELD_NUMBER_OF_CARDS },
+        MatchEntity,
+        CardArea::new(PLAYER_HAND_NUMBER_OF_CARDS, PLAY_HAND_LIMIT as usize),
         CardsOrder(vec![]),
-        Battlefield {},
-        build_sprite_bundle(width, translation_y)
+        CardZone::Hand,
+        PlayerHand {},
+        ScreenAnchor::new(ScreenAnchor::BOTTOM, Vec2::new(0.0, translation_y)),
+        build_sprite_bundle(width, 0.0),
     ));
 }
 
-fn spawn_player_hand(
-    mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-) {
-    let window = window_query.get_single().unwrap();
-    let width = area_with(PLAYER_HAND_NUMBER_OF_CARDS);
-    let translation_y = -window.height() / 2.0 + (CARD_SIZE.y / 2.0) + CARD_MARGIN;
+fn spawn_card(
+    commands: &mut Commands,
+    card: &Card,
+    card_definitions: &CardDefinitions,
+    face_assets: Option<&CardFaceAssets>,
+    asset_server: &AssetServer,
+    transform: Transform,
+) -> Entity {
+    let definition = card_definitions.get(&card.id);
+    let color = definition
+        .map(|definition| card_type_color(definition.card_type))
+        .unwrap_or(Color::GRAY);
 
     commands.spawn((
-        CardArea { number_of_cards: PLAYER_HAND_NUMBER_OF_CARDS },
-        PlayerHand {},
-        build_sprite_bundle(width, translation_y),
+        card.clone(),
+        SpriteBundle {
+            sprite: Sprite {
+                color,
+                custom_size: Some(CARD_SIZE),
+                ..default()
+            },
+            transform,
+            ..default()
+        }
     )).with_children(|parent| {
-        let mut rng = thread_rng();
-
-        for i in 0..PLAYER_HAND_NUMBER_OF_CARDS {
-            let translation = card_translation(PLAYER_HAND_NUMBER_OF_CARDS, i);
-            parent.spawn((
-                Card {},
-                SpriteBundle {
-                    sprite: Sprite {
-                        color: Color::rgb(rng.gen_range(0.0..1.0), rng.gen_range(0.0..1.0), rng.gen_range(0.0..1.0)),
-                        custom_size: Some(CARD_SIZE),
-                        ..default()
-                    },
-                    transform: Transform::from_translation(translation),
-                    ..default()
-                }
-            ));
+        if let (Some(definition), Some(face_assets)) = (definition, face_assets) {
+            spawn_card_face(definition, face_assets, asset_server, parent);
         }
-    });
+    }).id()
 }
 
-fn update_mouse_position(
-    mut cursor_moved_events: EventReader<CursorMoved>,
-    mut mouse_position: ResMut<MousePosition>,
+fn spawn_drawn_cards(
+    mut commands: Commands,
+    mut card_drawn_events: EventReader<CardDrawnEvent>,
+    mut hand_query: Query<(Entity, &mut CardsOrder), With<PlayerHand>>,
+    card_definitions: CardDefinitions,
+    face_assets: Option<Res<CardFaceAssets>>,
+    asset_server: Res<AssetServer>,
 ) {
-    for event in cursor_moved_events.iter() {
-        mouse_position.0 = event.position;
+    let Ok((hand_entity, mut cards_order)) = hand_query.get_single_mut() else {
+        return;
+    };
+    let cards: Vec<&Card> = card_drawn_events.iter()
+        .filter(|event| event.player == PlayerNumber::One)
+        .map(|event| &event.card)
+        .collect();
+    let number_of_cards = cards_order.0.len() + cards.len();
+
+    for card in cards {
+        info!("card drawn {:?}", card);
+        let transform = hand_card_transform(number_of_cards, cards_order.0.len());
+        let card_entity = spawn_card(&mut commands, card, &card_definitions, face_assets.as_deref(), &asset_server, transform);
+        commands.entity(hand_entity).add_child(card_entity);
+        cards_order.0.push(card_entity);
+    }
+}
+
+fn spawn_played_cards(
+    mut commands: Commands,
+    mut card_played_events: EventReader<CardPlayedEvent>,
+    mut battlefield_query: Query<(Entity, &Battlefield, &mut CardsOrder)>,
+    card_query: Query<&Card>,
+    card_definitions: CardDefinitions,
+    face_assets: Option<Res<CardFaceAssets>>,
+    asset_server: Res<AssetServer>,
+) {
+    for event in card_played_events.iter() {
+        if card_query.iter().any(|card| card.instance == event.card.instance) {
+            continue;
+        }
+
+        let Some((battlefield_entity, _, mut cards_order)) = battlefield_query.iter_mut()
+            .find(|(_, battlefield, _)| battlefield.0 == event.player) else {
+            continue;
+        };
+
+        info!("card played by {:?} {:?}", event.player, event.card);
+        let translation = card_translation(BATTLEFIELD_NUMBER_OF_CARDS, cards_order.0.len());
+        let card_entity = spawn_card(&mut commands, &event.card, &card_definitions, face_assets.as_deref(), &asset_server, Transform::from_translation(translation));
+        commands.entity(card_entity).insert(CardPlaced {});
+        commands.entity(battlefield_entity).add_child(card_entity);
+        cards_order.0.push(card_entity);
+    }
+}
+
+fn despawn_removed_cards(
+    mut commands: Commands,
+    mut card_removed_events: EventReader<CardRemovedEvent>,
+    card_query: Query<(Entity, &Card)>,
+    mut area_query: Query<&mut CardsOrder>,
+) {
+    for event in card_removed_events.iter() {
+        for (card_entity, card) in card_query.iter() {
+            if card.instance == event.card.instance {
+                info!("card removed {:?}", card);
+                commands.entity(card_entity).despawn_recursive();
+
+                for mut cards_order in area_query.iter_mut() {
+                    cards_order.0.retain(|entity| *entity != card_entity);
+                }
+            }
+        }
     }
 }
 
 fn move_card(
-    mouse_position: Res<MousePosition>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    mut card_query: Query<(&mut Transform, &GlobalTransform), With<CardBeingDragged>>,
+    mut drag_events: EventReader<CardDragEvent>,
+    mut card_query: Query<(&mut Transform, &GlobalTransform), With<Card>>,
 ) {
-    if mouse_position.is_changed() {
-        if let Ok((mut transform, global_transform)) = card_query.get_single_mut() {
-            let window = window_query.get_single().unwrap();
+    for event in drag_events.iter() {
+        if let Ok((mut transform, global_transform)) = card_query.get_mut(event.entity) {
             let translation = global_transform.translation();
-            let delta = mouse_translation(mouse_position.0, window, translation.z + 1.0) - translation;
-            info!("delta {:?}", delta);
+            let delta = event.position.extend(translation.z + 1.0) - translation;
             transform.translation += delta;
         }
     }
 }
 
+fn fade_card_rejected(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut card_query: Query<(Entity, &mut Sprite, &mut CardRejected)>,
+) {
+    for (entity, mut sprite, mut card_rejected) in card_query.iter_mut() {
+        card_rejected.timer.tick(time.delta());
+
+        if card_rejected.timer.finished() {
+            sprite.color = card_rejected.original_color;
+            commands.entity(entity).remove::<CardRejected>();
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
 pub fn reorder_cards(
+    mut commands: Commands,
     battlefield_query: Query<(&CardArea, &CardsOrder), (With<Battlefield>, Changed<CardsOrder>)>,
-    mut card_query: Query<&mut Transform, With<CardPlaced>>,
+    card_query: Query<(&Transform, Option<&Tween<TranslationLens>>), (With<CardPlaced>, Without<CardBeingDragged>)>,
 ) {
-    if let Ok((card_area, cards_order)) = battlefield_query.get_single() {
+    for (card_area, cards_order) in battlefield_query.iter() {
         for (position, card_entity) in cards_order.0.iter().enumerate() {
-            if let Ok(mut card_transform) = card_query.get_mut(*card_entity) {
-                card_transform.translation = card_translation(card_area.number_of_cards, position);
+            if let Ok((card_transform, tween)) = card_query.get(*card_entity) {
+                let target = Transform::from_translation(card_translation(card_area.number_of_cards, position));
+                tween_card(&mut commands, *card_entity, card_transform, tween, target);
             }
         }
     }
 }
 
-fn mouse_pressed(
+#[allow(clippy::type_complexity)]
+fn reorder_hand_cards(
     mut commands: Commands,
-    mut mouse_button_input_events: EventReader<MouseButtonInput>,
-    // mouse_button_input: Res<Input<MouseButton>>,
-    mouse_position: Res<MousePosition>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    card_query: Query<(Entity, &Transform, &GlobalTransform), (With<Card>, Without<CardBeingDragged>)>,
+    hand_query: Query<&CardsOrder, (With<PlayerHand>, Changed<CardsOrder>)>,
+    card_query: Query<(&Transform, Option<&Tween<TranslationLens>>), (With<Card>, Without<CardPlaced>, Without<CardBeingDragged>)>,
 ) {
-    let window = window_query.get_single().unwrap();
-
-    for event in mouse_button_input_events.iter() {
-        if event.button == MouseButton::Left && event.state == ButtonState::Pressed {
-            // if mouse_button_input.pressed(MouseButton::Left) {
-            let mouse_size = Vec2::new(1.0, 1.0);
-
-            for (entity, transform, global_transform) in card_query.iter() {
-                let card_pos = global_transform.translation();
-                let mouse_pos = mouse_translation(mouse_position.0, window, card_pos.z);
-                let collision = collide(
-                    card_pos,
-                    CARD_SIZE,
-                    mouse_pos,
-                    mouse_size,
-                );
-
-                if let Some(collision) = collision {
-                    info!("card collision {:?}", collision);
-                    commands.entity(entity).insert(CardBeingDragged {
-                        original_translation: transform.translation,
-                    });
-                }
+    for cards_order in hand_query.iter() {
+        let number_of_cards = cards_order.0.len();
+
+        for (position, card_entity) in cards_order.0.iter().enumerate() {
+            if let Ok((card_transform, tween)) = card_query.get(*card_entity) {
+                tween_card(&mut commands, *card_entity, card_transform, tween, hand_card_transform(number_of_cards, position));
             }
         }
     }
 }
 
+#[allow(clippy::type_complexity)]
+fn start_card_drag(
+    mut commands: Commands,
+    mut drag_start_events: EventReader<CardDragStartEvent>,
+    card_query: Query<(&Transform, Option<&Tween<TranslationLens>>, Option<&CardHovered>), Without<CardBeingDragged>>,
+) {
+    for event in drag_start_events.iter() {
+        if let Ok((transform, tween, hovered)) = card_query.get(event.entity) {
+            commands.entity(event.entity)
+                .remove::<(Tween<TranslationLens>, Tween<RotationLens>)>()
+                .insert(CardBeingDragged {
+                    original_translation: hovered.map(|hovered| hovered.rest_translation)
+                        .or(tween.map(|tween| tween.lens.end))
+                        .unwrap_or(transform.translation),
+                });
+        }
+    }
+}
 
-fn mouse_released(
+
+#[allow(clippy::type_complexity)]
+fn preview_card_drop(
     mut commands: Commands,
-    mut mouse_button_input_events: EventReader<MouseButtonInput>,
-    mut area_query: Query<(Entity, &Sprite, &Transform, &GlobalTransform, &CardArea, &mut CardsOrder), With<Battlefield>>,
-    area_children_query: Query<&Children, With<Battlefield>>,
-    mut card_query: Query<(Entity, &mut Transform, &GlobalTransform, &CardBeingDragged), (Without<Battlefield>, Without<CardPlaced>)>,
-    mut card_query_2: Query<(Entity, &mut Transform, &GlobalTransform, &CardBeingDragged), (Without<Battlefield>, With<CardPlaced>)>,
+    mut previewing: Local<bool>,
+    dragged_query: Query<(Entity, &Card, &Parent, &GlobalTransform), With<CardBeingDragged>>,
+    mut area_query: Query<(Entity, &CardZone, &CardArea, &mut Sprite, &Transform, &GlobalTransform, &CardsOrder), Without<Card>>,
+    card_query: Query<(&Transform, Option<&Tween<TranslationLens>>), (With<Card>, Without<CardBeingDragged>, Without<CardArea>)>,
+    card_move_rules: CardMoveRules,
 ) {
-    for (card_entity, mut card_transform, card_global_transform, card_being_dragged) in card_query.iter_mut() {
-        for event in mouse_button_input_events.iter() {
-            if let Ok((play_area_entity, sprite, transform, global_transform, card_area, mut cards_order)) = area_query.get_single_mut() {
-                let area_size = sprite.custom_size.unwrap() * transform.scale.truncate();
-
-                if event.button == MouseButton::Left && event.state == ButtonState::Released {
-                    commands.entity(card_entity).remove::<CardBeingDragged>();
-
-                    let collision = collide(
-                        global_transform.translation(),
-                        area_size,
-                        card_global_transform.translation(),
-                        CARD_SIZE,
-                    );
-
-                    if let Some(collision) = collision {
-                        info!("area collision {:?}", collision);
-                        commands.entity(play_area_entity).add_child(card_entity);
-                        cards_order.0.push(card_entity);
-                        let position = area_children_query.get_single()
-                            .map(|children| children.len())
-                            .unwrap_or_else(|_| 0);
-                        commands.entity(card_entity).insert(CardPlaced {});
-
-                        card_transform.translation = card_translation(card_area.number_of_cards, position);
-                    } else {
-                        card_transform.translation = card_being_dragged.original_translation;
-                    }
-                }
+    let dragged = dragged_query.iter().next();
+
+    if dragged.is_none() && !*previewing {
+        return;
+    }
+    *previewing = dragged.is_some();
+
+    let preview = dragged.and_then(|(card_entity, card, parent, card_global_transform)| {
+        let card_position = card_global_transform.translation();
+        let (_, from_zone, ..) = area_query.get(parent.get()).ok()?;
+        let target = area_query.iter()
+            .filter_map(|(entity, _, _, sprite, transform, global_transform, _)| {
+                area_distance(sprite, transform, global_transform, card_position).map(|distance| (entity, distance))
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(entity, _)| entity);
+
+        Some((card_entity, card, *from_zone, parent.get(), card_position, target))
+    });
+
+    for (area_entity, zone, card_area, mut sprite, _, area_global_transform, cards_order) in area_query.iter_mut() {
+        let mut slots: Vec<Option<Entity>> = cards_order.0.iter().copied().map(Some).collect();
+        sprite.color = CARD_AREA_COLOR;
+
+        if let Some((card_entity, card, from_zone, from_area, card_position, target)) = preview {
+            let card_move = CardMove {
+                card: card.clone(),
+                from: from_zone,
+                to: *zone,
+            };
+            let legal = card_move_rules.is_legal(&card_move)
+                && (area_entity == from_area || card_area.accepts(cards_order));
+
+            sprite.color = if !legal {
+                CARD_AREA_DIMMED_COLOR
+            } else if target == Some(area_entity) {
+                CARD_AREA_HIGHLIGHT_COLOR
+            } else {
+                CARD_AREA_COLOR
+            };
+
+            slots.retain(|slot| *slot != Some(card_entity));
+
+            if legal && target == Some(area_entity) {
+                let position = insertion_position(*zone, card_area, area_global_transform, cards_order, card_entity, card_position);
+                slots.insert(position.min(slots.len()), None);
+            }
+        }
+
+        let number_of_cards = slots.len();
+        for (position, slot) in slots.into_iter().enumerate() {
+            let Some(card_entity) = slot else {
+                continue;
+            };
+
+            if let Ok((card_transform, tween)) = card_query.get(card_entity) {
+                let target = layout_transform(*zone, card_area, number_of_cards, position);
+                tween_card(&mut commands, card_entity, card_transform, tween, target);
             }
         }
     }
+}
 
-    for (card_entity, mut card_transform, card_global_transform, card_being_dragged) in card_query_2.iter_mut() {
-        for event in mouse_button_input_events.iter() {
-            if let Ok((_, sprite, transform, global_transform, card_area, mut cards_order)) = area_query.get_single_mut() {
-                let area_size = sprite.custom_size.unwrap() * transform.scale.truncate();
-
-                if event.button == MouseButton::Left && event.state == ButtonState::Released {
-                    commands.entity(card_entity).remove::<CardBeingDragged>();
-
-                    let collision = collide(
-                        global_transform.translation(),
-                        area_size,
-                        card_global_transform.translation(),
-                        CARD_SIZE,
-                    );
-
-                    if let Some(collision) = collision {
-                        info!("area collision {:?}", collision);
-                        info!("card_global_transform.translation().x {:?}", card_global_transform.translation().x);
-                        info!("global_transform.translation().x {:?}", global_transform.translation().x);
-                        let card_translation_x = card_global_transform.translation().x;
-                        let multiplier = if card_translation_x > card_being_dragged.original_translation.x { -1.0 } else if card_translation_x < card_being_dragged.original_translation.x { 1.0 } else { 0.0 };
-                        let negative_with = area_with(card_area.number_of_cards) / 2.0;
-                        let relative_hover_x = card_translation_x + negative_with + multiplier * CARD_SIZE.x / 2.0;
-                        info!("relative_hover_x {:?}", relative_hover_x);
-
-                        let len = area_children_query.get_single()
-                            .map(|children| children.len())
-                            .unwrap_or_else(|_| 0);
-
-                        let to_position = ((relative_hover_x / CARD_SIZE.x).floor() as usize).min(len - 1);
-                        info!("position {:?}", to_position);
-
-                        if let Some(from_position) = cards_order.0.iter().position(|entity| *entity == card_entity) {
-                            cards_order.0.remove(from_position);
-                        }
-
-                        cards_order.0.insert(to_position, card_entity);
-
-                        card_transform.translation = card_translation(card_area.number_of_cards, to_position);
-                    } else {
-                        card_transform.translation = card_being_dragged.original_translation;
-                    }
-                }
+fn cancel_card_drags(
+    mut commands: Commands,
+    mut drag_cancel_events: EventReader<CardDragCancelEvent>,
+    card_query: Query<(&Transform, &CardBeingDragged)>,
+) {
+    for event in drag_cancel_events.iter() {
+        if let Ok((card_transform, card_being_dragged)) = card_query.get(event.entity) {
+            commands.entity(event.entity).remove::<CardBeingDragged>();
+            let original_transform = Transform::from_translation(card_being_dragged.original_translation)
+                .with_rotation(card_transform.rotation);
+            tween_card(&mut commands, event.entity, card_transform, None, original_transform);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn drop_cards(
+    mut commands: Commands,
+    mut drop_events: EventReader<CardDropEvent>,
+    mut area_query: Query<(Entity, &CardZone, &CardArea, &Sprite, &Transform, &GlobalTransform, &mut CardsOrder)>,
+    mut card_query: Query<(Entity, &Card, &Parent, &mut Sprite, &mut Transform, &GlobalTransform, &CardBeingDragged, Option<&CardRejected>), Without<CardArea>>,
+    mut card_move_rules: CardMoveRules,
+) {
+    for event in drop_events.iter() {
+        let Ok((card_entity, card, parent, mut card_sprite, mut card_transform, card_global_transform, card_being_dragged, card_rejected)) = card_query.get_mut(event.entity) else {
+            continue;
+        };
+        commands.entity(card_entity).remove::<CardBeingDragged>();
+
+        let card_position = card_global_transform.translation();
+        let original_transform = Transform::from_translation(card_being_dragged.original_translation)
+            .with_rotation(card_transform.rotation);
+        let from_area = parent.get();
+        let from_zone = area_query.get(from_area).map(|(_, zone, ..)| *zone);
+        let to_area = area_query.iter()
+            .filter_map(|(entity, zone, _, sprite, transform, global_transform, _)| {
+                area_distance(sprite, transform, global_transform, card_position)
+                    .map(|distance| (entity, *zone, distance))
+            })
+            .min_by(|(.., a), (.., b)| a.total_cmp(b));
+
+        let (Ok(from_zone), Some((to_area, to_zone, _))) = (from_zone, to_area) else {
+            tween_card(&mut commands, card_entity, &card_transform, None, original_transform);
+            continue;
+        };
+
+        info!("area collision {:?} -> {:?}", from_zone, to_zone);
+        let card_move = CardMove {
+            card: card.clone(),
+            from: from_zone,
+            to: to_zone,
+        };
+
+        if to_area == from_area {
+            let Ok((_, _, card_area, _, _, area_global_transform, mut cards_order)) = area_query.get_mut(to_area) else {
+                continue;
+            };
+
+            if !card_move_rules.is_legal(&card_move) {
+                tween_card(&mut commands, card_entity, &card_transform, None, original_transform);
+                continue;
+            }
+
+            let len = cards_order.0.len();
+            let to_position = insertion_position(to_zone, card_area, area_global_transform, &cards_order, card_entity, card_position);
+
+            if let Some(from_position) = cards_order.0.iter().position(|entity| *entity == card_entity) {
+                cards_order.0.remove(from_position);
+            }
+
+            cards_order.0.insert(to_position, card_entity);
+            let target = layout_transform(to_zone, card_area, len, to_position);
+            tween_card(&mut commands, card_entity, &card_transform, None, target);
+            continue;
+        }
+
+        let accepts = area_query.get(to_area)
+            .map(|(_, _, card_area, _, _, _, cards_order)| card_area.accepts(cards_order))
+            .unwrap_or(false);
+
+        if !accepts || !card_move_rules.apply(&card_move) {
+            let original_color = card_rejected
+                .map(|card_rejected| card_rejected.original_color)
+                .unwrap_or(card_sprite.color);
+            card_sprite.color = CARD_REJECTED_COLOR;
+            commands.entity(card_entity).insert(CardRejected {
+                timer: Timer::from_seconds(CARD_REJECTED_SECONDS, TimerMode::Once),
+                original_color,
+            });
+            tween_card(&mut commands, card_entity, &card_transform, None, original_transform);
+            continue;
+        }
+
+        if let Ok((.., mut cards_order)) = area_query.get_mut(from_area) {
+            cards_order.0.retain(|entity| *entity != card_entity);
+        }
+
+        let Ok((_, _, card_area, _, _, area_global_transform, mut cards_order)) = area_query.get_mut(to_area) else {
+            continue;
+        };
+        let position = insertion_position(to_zone, card_area, area_global_transform, &cards_order, card_entity, card_position);
+        cards_order.0.insert(position, card_entity);
+        card_transform.translation = area_global_transform.affine().inverse().transform_point3(card_position);
+        commands.entity(to_area).add_child(card_entity);
+
+        match to_zone {
+            CardZone::Battlefield(_) => {
+                commands.entity(card_entity).insert(CardPlaced {});
+            }
+            CardZone::Hand => {
+                commands.entity(card_entity).remove::<CardPlaced>();
             }
         }
+        let target = layout_transform(to_zone, card_area, cards_order.0.len(), position);
+        tween_card(&mut commands, card_entity, &card_transform, None, target);
     }
-}
\ No newline at end of file
+}
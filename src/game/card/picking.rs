@@ -0,0 +1,263 @@
+use bevy::{
+    input::{
+        ButtonState,
+        mouse::MouseButtonInput,
+        touch::{TouchInput, TouchPhase},
+    },
+    prelude::*,
+    sprite::collide_aabb::collide,
+    window::PrimaryWindow,
+};
+
+use crate::game::combat::{Card, PlayerNumber};
+use crate::game::layout::cursor_to_world;
+
+use super::CARD_SIZE;
+
+pub const TOUCH_DRAG_THRESHOLD: f32 = 8.0;
+pub const TOUCH_LONG_PRESS_SECONDS: f32 = 0.5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PointerEvent {
+    Moved(Vec2),
+    Touched(Vec2),
+    Pressed,
+    Released,
+    Exited,
+}
+
+#[derive(Resource, Default)]
+pub struct Picking {
+    pub position: Vec2,
+    pub present: bool,
+    pub hovered: Option<Entity>,
+    pub pressed: Option<Entity>,
+    pub dragged: Option<Entity>,
+}
+
+pub struct CardHoverEvent {
+    pub entity: Option<Entity>,
+}
+
+pub struct CardPressEvent {
+    pub entity: Entity,
+}
+
+pub struct CardDragStartEvent {
+    pub entity: Entity,
+}
+
+pub struct CardDragEvent {
+    pub entity: Entity,
+    pub position: Vec2,
+}
+
+pub struct CardDragCancelEvent {
+    pub entity: Entity,
+}
+
+#[allow(dead_code)]
+pub struct CardDropEvent {
+    pub entity: Entity,
+    pub position: Vec2,
+}
+
+#[derive(Default)]
+pub struct TouchTracking {
+    id: Option<u64>,
+    origin: Vec2,
+    started: f32,
+    dragging: bool,
+    long_pressed: bool,
+}
+
+pub fn topmost_card<'a>(
+    position: Vec2,
+    cards: impl Iterator<Item=(Entity, &'a Transform, &'a GlobalTransform)>,
+) -> Option<Entity> {
+    cards
+        .filter(|(_, transform, global_transform)| {
+            let card_position = global_transform.translation();
+            collide(card_position, CARD_SIZE * transform.scale.truncate(), position.extend(card_position.z), Vec2::ONE).is_some()
+        })
+        .max_by(|(_, _, a), (_, _, b)| a.translation().z.total_cmp(&b.translation().z))
+        .map(|(entity, ..)| entity)
+}
+
+pub(super) fn send_mouse_pointer_events(
+    mut cursor_moved_events: EventReader<CursorMoved>,
+    mut cursor_left_events: EventReader<CursorLeft>,
+    mut mouse_button_input_events: EventReader<MouseButtonInput>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut pointer_events: EventWriter<PointerEvent>,
+) {
+    for event in cursor_moved_events.iter() {
+        let position = cursor_to_world(event.position, camera_query.get_single().ok(), window_query.get_single().ok());
+        pointer_events.send(PointerEvent::Moved(position));
+    }
+
+    if cursor_left_events.iter().count() > 0 {
+        pointer_events.send(PointerEvent::Exited);
+    }
+
+    for event in mouse_button_input_events.iter() {
+        if event.button == MouseButton::Left {
+            pointer_events.send(match event.state {
+                ButtonState::Pressed => PointerEvent::Pressed,
+                ButtonState::Released => PointerEvent::Released,
+            });
+        }
+    }
+}
+
+pub(super) fn send_touch_pointer_events(
+    time: Res<Time>,
+    mut tracking: Local<TouchTracking>,
+    mut touch_input_events: EventReader<TouchInput>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut pointer_events: EventWriter<PointerEvent>,
+) {
+    let camera = camera_query.get_single().ok();
+    let window = window_query.get_single().ok();
+
+    for event in touch_input_events.iter() {
+        let viewport_position = window
+            .map(|window| Vec2::new(event.position.x, window.height() - event.position.y))
+            .unwrap_or(event.position);
+        let position = cursor_to_world(viewport_position, camera, window);
+
+        if event.phase != TouchPhase::Started && tracking.id != Some(event.id) {
+            continue;
+        }
+
+        match event.phase {
+            TouchPhase::Started => {
+                if tracking.id.is_some() {
+                    info!("touch rejected {:?}", event.id);
+                    continue;
+                }
+
+                *tracking = TouchTracking {
+                    id: Some(event.id),
+                    origin: position,
+                    started: time.elapsed_seconds(),
+                    ..default()
+                };
+                pointer_events.send(PointerEvent::Touched(position));
+            }
+            TouchPhase::Moved => {
+                if tracking.dragging || tracking.long_pressed {
+                    pointer_events.send(PointerEvent::Moved(position));
+                } else if position.distance(tracking.origin) > TOUCH_DRAG_THRESHOLD {
+                    tracking.dragging = true;
+                    pointer_events.send(PointerEvent::Moved(tracking.origin));
+                    pointer_events.send(PointerEvent::Pressed);
+                    pointer_events.send(PointerEvent::Moved(position));
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                if tracking.dragging {
+                    pointer_events.send(PointerEvent::Released);
+                }
+
+                pointer_events.send(PointerEvent::Exited);
+                *tracking = TouchTracking::default();
+            }
+        }
+    }
+
+    if tracking.id.is_some()
+        && !tracking.dragging
+        && !tracking.long_pressed
+        && time.elapsed_seconds() - tracking.started >= TOUCH_LONG_PRESS_SECONDS {
+        tracking.long_pressed = true;
+        pointer_events.send(PointerEvent::Moved(tracking.origin));
+    }
+}
+
+fn topmost_own_card(position: Vec2, card_query: &Query<(Entity, &Transform, &GlobalTransform, &Card)>) -> Option<Entity> {
+    let own_cards = card_query.iter()
+        .filter(|(.., card)| card.owner == PlayerNumber::One)
+        .map(|(entity, transform, global_transform, _)| (entity, transform, global_transform));
+
+    topmost_card(position, own_cards)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn pick_cards(
+    mut picking: ResMut<Picking>,
+    mut pointer_events: EventReader<PointerEvent>,
+    card_query: Query<(Entity, &Transform, &GlobalTransform, &Card)>,
+    mut hover_events: EventWriter<CardHoverEvent>,
+    mut press_events: EventWriter<CardPressEvent>,
+    mut drag_start_events: EventWriter<CardDragStartEvent>,
+    mut drag_events: EventWriter<CardDragEvent>,
+    mut drop_events: EventWriter<CardDropEvent>,
+) {
+    if picking.dragged.is_some_and(|entity| !card_query.contains(entity)) {
+        picking.dragged = None;
+    }
+
+    for event in pointer_events.iter() {
+        match *event {
+            PointerEvent::Moved(position) => {
+                picking.position = position;
+                picking.present = true;
+
+                if let Some(entity) = picking.dragged {
+                    drag_events.send(CardDragEvent { entity, position });
+                }
+            }
+            PointerEvent::Touched(position) => {
+                if picking.dragged.is_some() {
+                    continue;
+                }
+
+                // a touch is pressed as soon as it lands, the drag only starts once it moved far enough
+                picking.pressed = topmost_own_card(position, &card_query);
+                if let Some(entity) = picking.pressed {
+                    press_events.send(CardPressEvent { entity });
+                }
+            }
+            PointerEvent::Pressed => {
+                if picking.dragged.is_some() {
+                    continue;
+                }
+
+                if let Some(entity) = topmost_own_card(picking.position, &card_query) {
+                    info!("card picked {:?}", entity);
+                    if picking.pressed.take() != Some(entity) {
+                        press_events.send(CardPressEvent { entity });
+                    }
+                    drag_start_events.send(CardDragStartEvent { entity });
+                    picking.dragged = Some(entity);
+                }
+            }
+            PointerEvent::Released => {
+                picking.pressed = None;
+                if let Some(entity) = picking.dragged.take() {
+                    drop_events.send(CardDropEvent { entity, position: picking.position });
+                }
+            }
+            PointerEvent::Exited => {
+                picking.present = false;
+                picking.pressed = None;
+            }
+        }
+    }
+
+    let hovered = match picking.dragged {
+        None if picking.present => topmost_card(
+            picking.position,
+            card_query.iter().map(|(entity, transform, global_transform, _)| (entity, transform, global_transform)),
+        ),
+        _ => None,
+    };
+
+    if hovered != picking.hovered {
+        picking.hovered = hovered;
+        hover_events.send(CardHoverEvent { entity: hovered });
+    }
+}
@@ -0,0 +1,52 @@
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::*,
+};
+
+use crate::game::combat::{Card, CardPlays, PlayerNumber};
+
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CardZone {
+    Hand,
+    Battlefield(PlayerNumber),
+}
+
+#[derive(Clone, Debug)]
+pub struct CardMove {
+    pub card: Card,
+    pub from: CardZone,
+    pub to: CardZone,
+}
+
+#[derive(SystemParam)]
+pub struct CardMoveRules<'w, 's> {
+    card_plays: CardPlays<'w, 's>,
+}
+
+impl<'w, 's> CardMoveRules<'w, 's> {
+    pub fn is_legal(&self, card_move: &CardMove) -> bool {
+        match (card_move.from, card_move.to) {
+            (CardZone::Hand, CardZone::Hand) => true,
+            (CardZone::Hand, CardZone::Battlefield(owner)) => {
+                owner == card_move.card.owner && self.card_plays.can_play(&card_move.card)
+            }
+            (CardZone::Battlefield(from), CardZone::Battlefield(to)) => {
+                from == to && from == card_move.card.owner && self.card_plays.can_move(&card_move.card)
+            }
+            (CardZone::Battlefield(_), CardZone::Hand) => self.card_plays.can_return(&card_move.card),
+        }
+    }
+
+    pub fn apply(&mut self, card_move: &CardMove) -> bool {
+        if !self.is_legal(card_move) {
+            info!("card move rejected {:?}", card_move);
+            return false;
+        }
+
+        match (card_move.from, card_move.to) {
+            (CardZone::Hand, CardZone::Battlefield(_)) => self.card_plays.play(&card_move.card),
+            (CardZone::Battlefield(_), CardZone::Hand) => self.card_plays.return_to_hand(&card_move.card),
+            _ => true,
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use bevy::{
+    prelude::*,
+    utils::HashMap,
+};
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+use crate::game::card::{CardDefinition, CardDefinitions, CardEffect, CardType};
+
+use super::{
+    BOARD_LIMIT, Board, Card, CardPlays, CurrentPlayerState, DeckRng, EndTurnRequested, Hand, MemoryCache, Player,
+    PlayerNumber, SystemIntegrity, SystemProtection,
+};
+
+pub const AI_THINK_DELAY_SECONDS: f32 = 0.8;
+const AI_DEFENSIVE_THRESHOLD: u8 = 50;
+
+pub struct AiContext {
+    pub playable: Vec<(Card, CardDefinition)>,
+    pub integrity: u8,
+    pub protection: u8,
+    pub board: usize,
+    pub opponent_integrity: u8,
+    pub opponent_protection: u8,
+    pub destroy_target: Option<CardDefinition>,
+}
+
+impl AiContext {
+    fn board_full(&self) -> bool {
+        self.board >= BOARD_LIMIT as usize
+    }
+
+    fn damage(&self, definition: &CardDefinition) -> u8 {
+        definition.effects.iter()
+            .chain(definition.side_effects.iter())
+            .map(|effect| match effect {
+                CardEffect::Damage(amount) => *amount,
+                _ => 0,
+            })
+            .fold(0, u8::saturating_add)
+    }
+
+    fn damage_score(&self, definition: &CardDefinition) -> u8 {
+        let destroy = definition.effects.iter()
+            .chain(definition.side_effects.iter())
+            .any(|effect| matches!(effect, CardEffect::Destroy));
+
+        // destroy is worth whatever the card it would remove from the opponent's hand could do
+        let destroyed = match (destroy, &self.destroy_target) {
+            (true, Some(target)) => self.damage(target).saturating_add(self.defense_score(target)),
+            _ => 0,
+        };
+
+        self.damage(definition).saturating_add(destroyed)
+    }
+
+    fn is_lethal(&self, definition: &CardDefinition) -> bool {
+        self.damage(definition) >= self.opponent_integrity.saturating_add(self.opponent_protection)
+    }
+
+    fn defense_score(&self, definition: &CardDefinition) -> u8 {
+        definition.effects.iter()
+            .chain(definition.side_effects.iter())
+            .map(|effect| match effect {
+                CardEffect::Heal(amount) | CardEffect::Protection(amount) => *amount,
+                _ => 0,
+            })
+            .fold(0, u8::saturating_add)
+    }
+
+    fn best_by(&self, score: impl Fn(&CardDefinition) -> u8) -> Option<Card> {
+        self.playable.iter()
+            .max_by_key(|(_, definition)| (score(definition), definition.memory_cost))
+            .map(|(card, _)| card.clone())
+    }
+}
+
+pub trait AiStrategy: Send + Sync {
+    fn choose(&self, context: &AiContext, rng: &mut StdRng) -> Option<Card>;
+}
+
+pub struct RandomStrategy;
+
+impl AiStrategy for RandomStrategy {
+    fn choose(&self, context: &AiContext, rng: &mut StdRng) -> Option<Card> {
+        context.playable.choose(rng).map(|(card, _)| card.clone())
+    }
+}
+
+pub struct GreedyDamageStrategy;
+
+impl AiStrategy for GreedyDamageStrategy {
+    fn choose(&self, context: &AiContext, _rng: &mut StdRng) -> Option<Card> {
+        if context.board_full() {
+            return None;
+        }
+
+        context.playable.iter()
+            .filter(|(_, definition)| context.is_lethal(definition))
+            .min_by_key(|(_, definition)| definition.memory_cost)
+            .map(|(card, _)| card.clone())
+            .or_else(|| context.best_by(|definition| context.damage_score(definition)))
+    }
+}
+
+pub struct DefensiveStrategy;
+
+impl AiStrategy for DefensiveStrategy {
+    fn choose(&self, context: &AiContext, _rng: &mut StdRng) -> Option<Card> {
+        if context.board_full() {
+            return None;
+        }
+
+        if context.integrity.saturating_add(context.protection) < AI_DEFENSIVE_THRESHOLD {
+            context.best_by(|definition| context.defense_score(definition))
+        } else {
+            context.best_by(|definition| match definition.card_type {
+                CardType::Defense => context.defense_score(definition),
+                _ => context.damage_score(definition).saturating_sub(context.opponent_protection) / 2,
+            })
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AiStrategyKind {
+    Random,
+    GreedyDamage,
+    Defensive,
+}
+
+impl FromStr for AiStrategyKind {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "random" => Ok(AiStrategyKind::Random),
+            "greedy" => Ok(AiStrategyKind::GreedyDamage),
+            "defensive" => Ok(AiStrategyKind::Defensive),
+            _ => Err(format!("unknown ai strategy {}", name)),
+        }
+    }
+}
+
+impl AiStrategyKind {
+    pub fn build(&self) -> Box<dyn AiStrategy> {
+        match self {
+            AiStrategyKind::Random => Box::new(RandomStrategy),
+            AiStrategyKind::GreedyDamage => Box::new(GreedyDamageStrategy),
+            AiStrategyKind::Defensive => Box::new(DefensiveStrategy),
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct AiSettings {
+    pub think_delay: f32,
+    pub strategies: HashMap<PlayerNumber, AiStrategyKind>,
+}
+
+impl Default for AiSettings {
+    fn default() -> Self {
+        Self {
+            think_delay: AI_THINK_DELAY_SECONDS,
+            strategies: HashMap::from_iter([(PlayerNumber::Two, AiStrategyKind::GreedyDamage)]),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct AiController {
+    pub strategy: Box<dyn AiStrategy>,
+    pub think_timer: Timer,
+}
+
+impl AiController {
+    pub fn new(strategy: Box<dyn AiStrategy>, think_delay: f32) -> Self {
+        Self {
+            strategy,
+            think_timer: Timer::from_seconds(think_delay, TimerMode::Repeating),
+        }
+    }
+
+    fn think(&mut self, delta: Duration) -> bool {
+        self.think_timer.duration().is_zero() || self.think_timer.tick(delta).just_finished()
+    }
+}
+
+pub(super) fn attach_ai_controllers(
+    mut commands: Commands,
+    ai_settings: Res<AiSettings>,
+    player_query: Query<(Entity, &Player), Added<Player>>,
+) {
+    for (entity, player) in player_query.iter() {
+        if let Some(strategy) = ai_settings.strategies.get(&player.0) {
+            info!("ai controller {:?} {:?}", player.0, strategy);
+            commands.entity(entity).insert(AiController::new(strategy.build(), ai_settings.think_delay));
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(super) fn run_ai(
+    time: Res<Time>,
+    current_player: Res<CurrentPlayerState>,
+    card_definitions: CardDefinitions,
+    mut rng: ResMut<DeckRng>,
+    mut ai_query: Query<(&Player, &mut AiController)>,
+    mut player_set: ParamSet<(
+        Query<(&Player, &Hand, &Board, &MemoryCache, &SystemIntegrity, &SystemProtection)>,
+        CardPlays,
+    )>,
+    mut end_turn_requested_events: EventWriter<EndTurnRequested>,
+) {
+    for (player, mut ai_controller) in ai_query.iter_mut() {
+        if player.0 != current_player.0 || !ai_controller.think(time.delta()) {
+            continue;
+        }
+
+        let context = {
+            let player_query = player_set.p0();
+            let Some((_, hand, board, memory_cache, system_integrity, system_protection)) = player_query.iter()
+                .find(|(other, ..)| other.0 == player.0) else {
+                continue;
+            };
+            let (opponent_integrity, opponent_protection, destroy_target) = player_query.iter()
+                .find(|(other, ..)| other.0 == player.0.opponent())
+                .map(|(_, hand, _, _, system_integrity, system_protection)| {
+                    let destroy_target = hand.0.last().and_then(|card| card_definitions.get(&card.id)).cloned();
+                    (system_integrity.0, system_protection.0, destroy_target)
+                })
+                .unwrap_or_default();
+
+            AiContext {
+                playable: hand.0.iter()
+                    .filter_map(|card| card_definitions.get(&card.id).map(|definition| (card.clone(), definition.clone())))
+                    .filter(|(_, definition)| definition.memory_cost <= memory_cache.0)
+                    .collect(),
+                integrity: system_integrity.0,
+                protection: system_protection.0,
+                board: board.0.len(),
+                opponent_integrity,
+                opponent_protection,
+                destroy_target,
+            }
+        };
+
+        match ai_controller.strategy.choose(&context, &mut rng.0) {
+            Some(card) if player_set.p1().play(&card) => {
+                info!("ai played {:?}", card);
+            }
+            _ => {
+                info!("ai ended turn {:?}", player.0);
+                end_turn_requested_events.send(EndTurnRequested(player.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+
+use super::{
+    Player, PlayerNumber, SYSTEM_INTEGRITY_MAX_VALUE, SYSTEM_PROTECTION_MAX_VALUE, SystemIntegrity,
+    SystemProtection,
+};
+
+pub struct DamageEvent {
+    pub target: PlayerNumber,
+    pub amount: u8,
+}
+
+pub struct HealEvent {
+    pub target: PlayerNumber,
+    pub amount: u8,
+}
+
+pub struct ShieldEvent {
+    pub target: PlayerNumber,
+    pub amount: u8,
+}
+
+impl SystemProtection {
+    pub fn absorb(&mut self, amount: u8) -> u8 {
+        let absorbed = self.0.min(amount);
+        self.0 -= absorbed;
+        amount - absorbed
+    }
+
+    pub fn shield(&mut self, amount: u8) {
+        self.0 = self.0.saturating_add(amount).min(SYSTEM_PROTECTION_MAX_VALUE);
+    }
+}
+
+impl SystemIntegrity {
+    pub fn damage(&mut self, amount: u8) {
+        self.0 = self.0.saturating_sub(amount);
+    }
+
+    pub fn heal(&mut self, amount: u8) {
+        self.0 = self.0.saturating_add(amount).min(SYSTEM_INTEGRITY_MAX_VALUE);
+    }
+}
+
+pub(super) fn resolve_shields(
+    mut shield_events: EventReader<ShieldEvent>,
+    mut player_query: Query<(&Player, &mut SystemProtection)>,
+) {
+    for event in shield_events.iter() {
+        for (player, mut system_protection) in player_query.iter_mut() {
+            if player.0 == event.target {
+                system_protection.shield(event.amount);
+                info!("shield {:?} +{:?} = {:?}", player.0, event.amount, system_protection.0);
+            }
+        }
+    }
+}
+
+pub(super) fn resolve_damage(
+    mut damage_events: EventReader<DamageEvent>,
+    mut player_query: Query<(&Player, &mut SystemIntegrity, &mut SystemProtection)>,
+) {
+    for event in damage_events.iter() {
+        for (player, mut system_integrity, mut system_protection) in player_query.iter_mut() {
+            if player.0 == event.target {
+                let remaining = system_protection.absorb(event.amount);
+                system_integrity.damage(remaining);
+                info!("damage {:?} -{:?} = {:?}", player.0, event.amount, system_integrity.0);
+            }
+        }
+    }
+}
+
+pub(super) fn resolve_heals(
+    mut heal_events: EventReader<HealEvent>,
+    mut player_query: Query<(&Player, &mut SystemIntegrity)>,
+) {
+    for event in heal_events.iter() {
+        for (player, mut system_integrity) in player_query.iter_mut() {
+            if player.0 == event.target {
+                system_integrity.heal(event.amount);
+                info!("heal {:?} +{:?} = {:?}", player.0, event.amount, system_integrity.0);
+            }
+        }
+    }
+}
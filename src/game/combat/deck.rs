@@ -0,0 +1,240 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+use crate::game::card::CardDefinitions;
+
+use super::{
+    Card, CardRemovedEvent, CARDS_DRAWN_AT_START, CARDS_DRAWN_EACH_TURN, CurrentPlayerState, PLAY_HAND_LIMIT, Player,
+    PlayerNumber,
+};
+
+#[derive(Resource)]
+pub struct DeckRng(pub StdRng);
+
+impl Default for DeckRng {
+    fn default() -> Self {
+        Self(StdRng::from_entropy())
+    }
+}
+
+impl DeckRng {
+    pub fn seeded(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+#[derive(Component, Default)]
+pub struct Deck {
+    pub draw_pile: Vec<Card>,
+    pub discard_pile: Vec<Card>,
+    pub exhaust_pile: Vec<Card>,
+}
+
+impl Deck {
+    pub fn new(cards: Vec<Card>) -> Self {
+        Self {
+            draw_pile: cards,
+            ..default()
+        }
+    }
+
+    pub fn shuffle(&mut self, rng: &mut StdRng) {
+        self.draw_pile.shuffle(rng);
+    }
+
+    pub fn reshuffle(&mut self, rng: &mut StdRng) {
+        self.draw_pile.append(&mut self.discard_pile);
+        self.shuffle(rng);
+    }
+
+    pub fn draw(&mut self, rng: &mut StdRng) -> Option<Card> {
+        if self.draw_pile.is_empty() {
+            self.reshuffle(rng);
+        }
+
+        self.draw_pile.pop()
+    }
+
+    pub fn discard(&mut self, card: Card) {
+        self.discard_pile.push(card);
+    }
+
+    pub fn exhaust(&mut self, card: Card) {
+        self.exhaust_pile.push(card);
+    }
+}
+
+#[derive(Component, Default)]
+pub struct Hand(pub Vec<Card>);
+
+impl Hand {
+    pub fn get(&self, instance: u32) -> Option<&Card> {
+        self.0.iter().find(|card| card.instance == instance)
+    }
+
+    pub fn remove(&mut self, instance: u32) -> Option<Card> {
+        self.0.iter()
+            .position(|card| card.instance == instance)
+            .map(|position| self.0.remove(position))
+    }
+}
+
+pub struct DrawEvent {
+    pub player: PlayerNumber,
+    pub count: u8,
+}
+
+pub struct CardDrawnEvent {
+    pub player: PlayerNumber,
+    pub card: Card,
+}
+
+pub struct DiscardEvent {
+    pub player: PlayerNumber,
+    pub instance: u32,
+}
+
+pub struct ExhaustEvent {
+    pub player: PlayerNumber,
+    pub instance: u32,
+}
+
+pub struct ReshuffleEvent(pub PlayerNumber);
+
+pub(super) fn build_decks(
+    mut commands: Commands,
+    mut next_instance: Local<u32>,
+    card_definitions: CardDefinitions,
+    player_query: Query<(Entity, &Player), Without<Deck>>,
+) {
+    if player_query.is_empty() {
+        return;
+    }
+
+    if let Some(library) = card_definitions.library() {
+        for (entity, player) in player_query.iter() {
+            info!("deck built {:?}", player.0);
+            let cards = library.starter_deck.iter()
+                .map(|id| {
+                    *next_instance += 1;
+                    Card {
+                        instance: *next_instance,
+                        id: id.clone(),
+                        owner: player.0,
+                    }
+                })
+                .collect();
+            commands.entity(entity).insert(Deck::new(cards));
+        }
+    }
+}
+
+pub(super) fn shuffle_decks(
+    mut rng: ResMut<DeckRng>,
+    mut deck_query: Query<&mut Deck, Added<Deck>>,
+) {
+    for mut deck in deck_query.iter_mut() {
+        deck.shuffle(&mut rng.0);
+    }
+}
+
+pub(super) fn deal_opening_hands(
+    player_query: Query<&Player, Added<Deck>>,
+    mut draw_events: EventWriter<DrawEvent>,
+) {
+    for player in player_query.iter() {
+        draw_events.send(DrawEvent {
+            player: player.0,
+            count: CARDS_DRAWN_AT_START,
+        });
+    }
+}
+
+pub(super) fn draw_turn_cards(
+    current_player: Res<CurrentPlayerState>,
+    mut draw_events: EventWriter<DrawEvent>,
+) {
+    draw_events.send(DrawEvent {
+        player: current_player.0,
+        count: CARDS_DRAWN_EACH_TURN,
+    });
+}
+
+pub(super) fn draw_cards(
+    mut rng: ResMut<DeckRng>,
+    mut draw_events: EventReader<DrawEvent>,
+    mut card_drawn_events: EventWriter<CardDrawnEvent>,
+    mut player_query: Query<(&Player, &mut Deck, &mut Hand)>,
+) {
+    for event in draw_events.iter() {
+        for (player, mut deck, mut hand) in player_query.iter_mut() {
+            if player.0 != event.player {
+                continue;
+            }
+
+            for _ in 0..event.count {
+                if hand.0.len() >= PLAY_HAND_LIMIT as usize {
+                    info!("hand limit reached {:?}", player.0);
+                    break;
+                }
+
+                if let Some(card) = deck.draw(&mut rng.0) {
+                    hand.0.push(card.clone());
+                    card_drawn_events.send(CardDrawnEvent {
+                        player: player.0,
+                        card,
+                    });
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn discard_cards(
+    mut discard_events: EventReader<DiscardEvent>,
+    mut card_removed_events: EventWriter<CardRemovedEvent>,
+    mut player_query: Query<(&Player, &mut Deck, &mut Hand)>,
+) {
+    for event in discard_events.iter() {
+        for (player, mut deck, mut hand) in player_query.iter_mut() {
+            if player.0 == event.player {
+                if let Some(card) = hand.remove(event.instance) {
+                    deck.discard(card.clone());
+                    card_removed_events.send(CardRemovedEvent { player: player.0, card });
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn exhaust_cards(
+    mut exhaust_events: EventReader<ExhaustEvent>,
+    mut card_removed_events: EventWriter<CardRemovedEvent>,
+    mut player_query: Query<(&Player, &mut Deck, &mut Hand)>,
+) {
+    for event in exhaust_events.iter() {
+        for (player, mut deck, mut hand) in player_query.iter_mut() {
+            if player.0 == event.player {
+                if let Some(card) = hand.remove(event.instance) {
+                    deck.exhaust(card.clone());
+                    card_removed_events.send(CardRemovedEvent { player: player.0, card });
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn reshuffle_decks(
+    mut rng: ResMut<DeckRng>,
+    mut reshuffle_events: EventReader<ReshuffleEvent>,
+    mut player_query: Query<(&Player, &mut Deck)>,
+) {
+    for event in reshuffle_events.iter() {
+        for (player, mut deck) in player_query.iter_mut() {
+            if player.0 == event.0 {
+                deck.reshuffle(&mut rng.0);
+            }
+        }
+    }
+}
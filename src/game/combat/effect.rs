@@ -0,0 +1,148 @@
+use std::collections::VecDeque;
+use std::ops::DerefMut;
+
+use bevy::prelude::*;
+
+use crate::game::card::{CardDefinitions, CardEffect};
+
+use super::{
+    Board, Card, CardPlayedEvent, CardReturnedEvent, CurrentPlayerState, DamageEvent, Deck, DiscardEvent, DrawEvent,
+    ExhaustEvent, HealEvent, Hand, MEMORY_CACHE_MAX_VALUE, MemoryCache, Player, PlayerNumber, ShieldEvent,
+};
+
+#[derive(Clone, Debug)]
+pub struct QueuedEffect {
+    pub source: Card,
+    pub effect: CardEffect,
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct EffectQueue(pub VecDeque<QueuedEffect>);
+
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct SideEffectQueue(pub VecDeque<QueuedEffect>);
+
+#[allow(dead_code)]
+pub struct CardRemovedEvent {
+    pub player: PlayerNumber,
+    pub card: Card,
+}
+
+pub(super) fn clear_effect_queues(
+    mut effect_queue: ResMut<EffectQueue>,
+    mut side_effect_queue: ResMut<SideEffectQueue>,
+) {
+    effect_queue.clear();
+    side_effect_queue.clear();
+}
+
+pub(super) fn queue_card_effects(
+    mut card_played_events: EventReader<CardPlayedEvent>,
+    card_definitions: CardDefinitions,
+    mut effect_queue: ResMut<EffectQueue>,
+    mut side_effect_queue: ResMut<SideEffectQueue>,
+) {
+    for event in card_played_events.iter() {
+        if let Some(definition) = card_definitions.get(&event.card.id) {
+            // memory gained from a card's main effects is applied on play so it can be spent this turn
+            for effect in definition.effects.iter().filter(|effect| !matches!(effect, CardEffect::Memory(_))) {
+                effect_queue.push_back(QueuedEffect {
+                    source: event.card.clone(),
+                    effect: *effect,
+                });
+            }
+
+            for effect in definition.side_effects.iter() {
+                side_effect_queue.push_back(QueuedEffect {
+                    source: event.card.clone(),
+                    effect: *effect,
+                });
+            }
+        }
+    }
+}
+
+pub(super) fn unqueue_returned_card_effects(
+    mut card_returned_events: EventReader<CardReturnedEvent>,
+    mut effect_queue: ResMut<EffectQueue>,
+    mut side_effect_queue: ResMut<SideEffectQueue>,
+) {
+    for event in card_returned_events.iter() {
+        effect_queue.retain(|queued| queued.source.instance != event.card.instance);
+        side_effect_queue.retain(|queued| queued.source.instance != event.card.instance);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn resolve_effect_queue<Queue: Resource + DerefMut<Target=VecDeque<QueuedEffect>>>(
+    mut queue: ResMut<Queue>,
+    mut player_query: Query<(&Player, &mut MemoryCache, &Hand)>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut heal_events: EventWriter<HealEvent>,
+    mut shield_events: EventWriter<ShieldEvent>,
+    mut draw_events: EventWriter<DrawEvent>,
+    mut discard_events: EventWriter<DiscardEvent>,
+    mut exhaust_events: EventWriter<ExhaustEvent>,
+) {
+    while let Some(QueuedEffect { source, effect }) = queue.pop_front() {
+        let owner = source.owner;
+        let opponent = owner.opponent();
+        info!("resolve effect {:?} from {:?}", effect, source);
+
+        match effect {
+            CardEffect::Damage(amount) => damage_events.send(DamageEvent { target: opponent, amount }),
+            CardEffect::Heal(amount) => heal_events.send(HealEvent { target: owner, amount }),
+            CardEffect::Protection(amount) => shield_events.send(ShieldEvent { target: owner, amount }),
+            CardEffect::Draw(count) => draw_events.send(DrawEvent { player: owner, count }),
+            CardEffect::Memory(amount) => {
+                for (player, mut memory_cache, _) in player_query.iter_mut() {
+                    if player.0 == owner {
+                        memory_cache.0 = memory_cache.0.saturating_add(amount).min(MEMORY_CACHE_MAX_VALUE);
+                    }
+                }
+            }
+            CardEffect::Discard(count) => {
+                for (player, _, hand) in player_query.iter() {
+                    if player.0 == opponent {
+                        for card in hand.0.iter().rev().take(count as usize) {
+                            discard_events.send(DiscardEvent {
+                                player: opponent,
+                                instance: card.instance,
+                            });
+                        }
+                    }
+                }
+            }
+            CardEffect::Destroy => {
+                for (player, _, hand) in player_query.iter() {
+                    if player.0 == opponent {
+                        if let Some(card) = hand.0.last() {
+                            exhaust_events.send(ExhaustEvent {
+                                player: opponent,
+                                instance: card.instance,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn discard_resolved_cards(
+    current_player: Res<CurrentPlayerState>,
+    mut player_query: Query<(&Player, &mut Board, &mut Deck)>,
+    mut card_removed_events: EventWriter<CardRemovedEvent>,
+) {
+    for (player, mut board, mut deck) in player_query.iter_mut() {
+        if player.0 != current_player.0 {
+            continue;
+        }
+
+        for card in board.0.drain(..) {
+            info!("card resolved {:?}", card);
+            deck.discard(card.clone());
+            card_removed_events.send(CardRemovedEvent { player: player.0, card });
+        }
+    }
+}
@@ -1,10 +1,34 @@
 use bevy::prelude::*;
 
-pub const MEMORY_CACHE_INITIAL_VALUE: u8 = 3;
-pub const SYSTEM_INTEGRITY_INITIAL_VALUE: u8 = 3;
+use crate::game::{GameState, MatchEntity};
+use crate::game::animation::tweens_finished;
+
+pub use ai::*;
+pub use damage::*;
+pub use deck::*;
+pub use effect::*;
+pub use outcome::*;
+pub use play::*;
+pub use turn::*;
+
+mod ai;
+mod damage;
+mod deck;
+mod effect;
+mod outcome;
+mod play;
+mod turn;
+
+pub const MEMORY_CACHE_INITIAL_VALUE: u8 = 5;
+pub const MEMORY_CACHE_MAX_VALUE: u8 = 9;
+pub const SYSTEM_INTEGRITY_INITIAL_VALUE: u8 = 100;
+pub const SYSTEM_INTEGRITY_MAX_VALUE: u8 = 100;
+pub const SYSTEM_PROTECTION_INITIAL_VALUE: u8 = 0;
+pub const SYSTEM_PROTECTION_MAX_VALUE: u8 = 99;
 pub const CARDS_DRAWN_AT_START: u8 = 3;
 pub const CARDS_DRAWN_EACH_TURN: u8 = 1;
 pub const PLAY_HAND_LIMIT: u8 = 7;
+pub const BOARD_LIMIT: u8 = 7;
 
 #[derive(Reflect, Clone, Copy, Debug, PartialEq, Default, Eq, Hash)]
 pub enum PlayerNumber {
@@ -14,29 +38,35 @@ pub enum PlayerNumber {
 }
 
 #[derive(Component, Reflect, Copy, Clone, Debug, PartialEq)]
-pub struct Player(PlayerNumber);
+pub struct Player(pub PlayerNumber);
 
 #[derive(Component, Reflect)]
-pub struct SystemIntegrity(u8);
+pub struct SystemIntegrity(pub u8);
 
 #[derive(Component, Reflect)]
-pub struct MemoryCache(u8);
+pub struct MemoryCache(pub u8);
+
+#[derive(Component, Reflect)]
+pub struct SystemProtection(pub u8);
 
 #[derive(Component)]
 pub struct HasPriority;
 
+#[derive(Component, Clone, Debug, PartialEq)]
 pub struct Card {
-    memory_cost: u8,
+    pub instance: u32,
+    pub id: String,
+    pub owner: PlayerNumber,
 }
 
-#[derive(Component)]
-pub struct Deck(Vec<Card>);
-
 #[derive(Bundle)]
 struct PlayerBundle {
     player: Player,
     system_integrity: SystemIntegrity,
     memory_cache: MemoryCache,
+    system_protection: SystemProtection,
+    hand: Hand,
+    board: Board,
 }
 
 #[derive(States, Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
@@ -51,8 +81,8 @@ pub enum TurnState {
 
 pub struct CombatPlugin;
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
-pub struct CurrentPlayerState(PlayerNumber);
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct CombatSystemSet;
 
 impl Plugin for CombatPlugin {
     fn build(&self, app: &mut App) {
@@ -60,18 +90,101 @@ impl Plugin for CombatPlugin {
             .register_type::<Player>()
             .register_type::<SystemIntegrity>()
             .register_type::<MemoryCache>()
+            .register_type::<SystemProtection>()
             .add_state::<TurnState>()
-            .add_startup_system(spawn_player(Player(PlayerNumber::One)))
-            .add_startup_system(spawn_player(Player(PlayerNumber::Two)));
+            .init_resource::<CurrentPlayerState>()
+            .add_event::<TurnStarted>()
+            .add_event::<TurnEnded>()
+            .add_event::<PhaseChanged>()
+            .add_event::<EndTurnRequested>()
+            .init_resource::<DeckRng>()
+            .add_event::<DrawEvent>()
+            .add_event::<CardDrawnEvent>()
+            .add_event::<DiscardEvent>()
+            .add_event::<ExhaustEvent>()
+            .add_event::<ReshuffleEvent>()
+            .init_resource::<PlayedThisTurn>()
+            .add_event::<CardPlayedEvent>()
+            .add_event::<CardReturnedEvent>()
+            .add_event::<DamageEvent>()
+            .add_event::<HealEvent>()
+            .add_event::<ShieldEvent>()
+            .init_resource::<EffectQueue>()
+            .init_resource::<SideEffectQueue>()
+            .add_event::<CardRemovedEvent>()
+            .init_resource::<AiSettings>()
+            .configure_set(CombatSystemSet.run_if(in_state(GameState::InMatch)))
+            .add_system(spawn_player(Player(PlayerNumber::One)).in_schedule(OnEnter(GameState::InMatch)))
+            .add_system(spawn_player(Player(PlayerNumber::Two)).in_schedule(OnEnter(GameState::InMatch)))
+            .add_system(reset_turn.in_schedule(OnEnter(GameState::InMatch)))
+            .add_system(clear_effect_queues.in_schedule(OnEnter(GameState::InMatch)))
+            .add_system(reset_played_this_turn.in_schedule(OnEnter(GameState::InMatch)))
+            .add_system(start_turn.in_schedule(OnEnter(TurnState::DrawCards)))
+            .add_system(draw_turn_cards.in_schedule(OnEnter(TurnState::DrawCards)))
+            .add_system(refill_memory_cache.in_schedule(OnEnter(TurnState::DrawCards)))
+            .add_system(reset_played_this_turn.in_schedule(OnEnter(TurnState::DrawCards)))
+            .add_system(discard_resolved_cards.in_schedule(OnEnter(TurnState::DrawCards)))
+            .add_system(pass_priority.in_schedule(OnExit(TurnState::EndTurn)))
+            .add_systems((
+                send_phase_changed.run_if(state_changed::<TurnState>()),
+                run_ai.after(draw_cards).in_set(OnUpdate(TurnState::PlayCards)),
+                end_play_cards.after(run_ai).in_set(OnUpdate(TurnState::PlayCards)),
+                build_decks,
+                shuffle_decks.after(build_decks),
+                deal_opening_hands.after(shuffle_decks),
+                draw_cards.after(deal_opening_hands),
+                discard_cards.after(draw_cards),
+                exhaust_cards.after(draw_cards),
+                reshuffle_decks.after(discard_cards),
+                queue_card_effects,
+                unqueue_returned_card_effects.after(queue_card_effects),
+                attach_ai_controllers,
+            ).in_set(CombatSystemSet))
+            .add_system(resolve_effect_queue::<EffectQueue>
+                .before(resolve_shields)
+                .in_set(OnUpdate(TurnState::ResolveEffects))
+                .in_set(CombatSystemSet))
+            .add_system(resolve_effect_queue::<SideEffectQueue>
+                .before(resolve_shields)
+                .in_set(OnUpdate(TurnState::ResolveSideEffects))
+                .in_set(CombatSystemSet))
+            .add_systems((resolve_shields, resolve_damage, resolve_heals)
+                .chain()
+                .distributive_run_if(resolving_effects)
+                .in_set(CombatSystemSet))
+            .add_system(detect_match_end.after(resolve_heals).in_set(CombatSystemSet))
+            .add_system(advance_turn_state
+                .after(detect_match_end)
+                .run_if(not(in_state(TurnState::PlayCards)))
+                .run_if(tweens_finished)
+                .in_set(CombatSystemSet));
+    }
+}
+
+pub struct CombatInputPlugin;
+
+impl Plugin for CombatInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(request_end_turn_on_key
+            .before(end_play_cards)
+            .in_set(OnUpdate(TurnState::PlayCards))
+            .in_set(CombatSystemSet));
     }
 }
 
 fn spawn_player(player: Player) -> impl FnMut(Commands) {
     move |mut commands| {
-        commands.spawn(PlayerBundle {
+        let mut entity_commands = commands.spawn((MatchEntity, PlayerBundle {
             player,
             system_integrity: SystemIntegrity(SYSTEM_INTEGRITY_INITIAL_VALUE),
             memory_cache: MemoryCache(MEMORY_CACHE_INITIAL_VALUE),
-        });
+            system_protection: SystemProtection(SYSTEM_PROTECTION_INITIAL_VALUE),
+            hand: Hand::default(),
+            board: Board::default(),
+        }));
+
+        if player.0 == PlayerNumber::default() {
+            entity_commands.insert(HasPriority);
+        }
     }
 }
\ No newline at end of file
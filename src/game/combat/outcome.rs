@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+use crate::game::GameState;
+
+use super::{Player, PlayerNumber, SystemIntegrity};
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct MatchResult {
+    pub winner: PlayerNumber,
+}
+
+pub(super) fn detect_match_end(
+    mut commands: Commands,
+    player_query: Query<(&Player, &SystemIntegrity), Changed<SystemIntegrity>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (player, system_integrity) in player_query.iter() {
+        if system_integrity.0 == 0 {
+            let winner = player.0.opponent();
+            info!("match ended, winner {:?}", winner);
+            commands.insert_resource(MatchResult { winner });
+            next_state.set(GameState::GameOver);
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::*,
+};
+
+use crate::game::card::{CardDefinitions, CardEffect};
+
+use super::{
+    BOARD_LIMIT, Card, CurrentPlayerState, Hand, MEMORY_CACHE_INITIAL_VALUE, MEMORY_CACHE_MAX_VALUE,
+    MemoryCache, PLAY_HAND_LIMIT, Player, PlayerNumber, TurnState,
+};
+
+#[derive(Component, Default)]
+pub struct Board(pub Vec<Card>);
+
+impl Board {
+    pub fn get(&self, instance: u32) -> Option<&Card> {
+        self.0.iter().find(|card| card.instance == instance)
+    }
+
+    pub fn remove(&mut self, instance: u32) -> Option<Card> {
+        self.0.iter()
+            .position(|card| card.instance == instance)
+            .map(|position| self.0.remove(position))
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct PlayedThisTurn(pub Vec<u32>);
+
+pub struct CardPlayedEvent {
+    pub player: PlayerNumber,
+    pub card: Card,
+}
+
+#[allow(dead_code)]
+pub struct CardReturnedEvent {
+    pub player: PlayerNumber,
+    pub card: Card,
+}
+
+#[derive(SystemParam)]
+pub struct CardPlays<'w, 's> {
+    state: Res<'w, State<TurnState>>,
+    current_player: Res<'w, CurrentPlayerState>,
+    card_definitions: CardDefinitions<'w>,
+    played_this_turn: ResMut<'w, PlayedThisTurn>,
+    player_query: Query<'w, 's, (&'static Player, &'static mut MemoryCache, &'static mut Hand, &'static mut Board)>,
+    card_played_events: EventWriter<'w, CardPlayedEvent>,
+    card_returned_events: EventWriter<'w, CardReturnedEvent>,
+}
+
+impl<'w, 's> CardPlays<'w, 's> {
+    pub fn memory_cost(&self, card: &Card) -> Option<u8> {
+        self.card_definitions.get(&card.id).map(|definition| definition.memory_cost)
+    }
+
+    pub fn memory_gain(&self, card: &Card) -> u8 {
+        self.card_definitions.get(&card.id)
+            .map(|definition| definition.effects.iter()
+                .map(|effect| match effect {
+                    CardEffect::Memory(amount) => *amount,
+                    _ => 0,
+                })
+                .fold(0, u8::saturating_add))
+            .unwrap_or_default()
+    }
+
+    pub fn can_move(&self, card: &Card) -> bool {
+        self.state.0 == TurnState::PlayCards && self.current_player.0 == card.owner
+    }
+
+    pub fn can_play(&self, card: &Card) -> bool {
+        if !self.can_move(card) {
+            return false;
+        }
+
+        let Some(memory_cost) = self.memory_cost(card) else {
+            return false;
+        };
+
+        self.player_query.iter()
+            .any(|(player, memory_cache, hand, board)| {
+                player.0 == card.owner
+                    && memory_cache.0 >= memory_cost
+                    && hand.get(card.instance).is_some()
+                    && board.0.len() < BOARD_LIMIT as usize
+            })
+    }
+
+    pub fn play(&mut self, card: &Card) -> bool {
+        if !self.can_play(card) {
+            info!("card rejected {:?}", card);
+            return false;
+        }
+
+        let memory_cost = self.memory_cost(card).unwrap_or_default();
+        let memory_gain = self.memory_gain(card);
+
+        for (player, mut memory_cache, mut hand, mut board) in self.player_query.iter_mut() {
+            if player.0 == card.owner {
+                memory_cache.0 = (memory_cache.0 - memory_cost).saturating_add(memory_gain).min(MEMORY_CACHE_MAX_VALUE);
+                if let Some(card) = hand.remove(card.instance) {
+                    board.0.push(card);
+                }
+            }
+        }
+
+        info!("card played {:?}", card);
+        self.played_this_turn.0.push(card.instance);
+        self.card_played_events.send(CardPlayedEvent {
+            player: card.owner,
+            card: card.clone(),
+        });
+
+        true
+    }
+
+    pub fn can_return(&self, card: &Card) -> bool {
+        if !self.can_move(card) || !self.played_this_turn.0.contains(&card.instance) {
+            return false;
+        }
+
+        let memory_gain = self.memory_gain(card);
+
+        // a card can't be taken back once the memory it gave has been spent
+        self.player_query.iter()
+            .any(|(player, memory_cache, hand, board)| {
+                player.0 == card.owner
+                    && memory_cache.0 >= memory_gain
+                    && board.get(card.instance).is_some()
+                    && hand.0.len() < PLAY_HAND_LIMIT as usize
+            })
+    }
+
+    pub fn return_to_hand(&mut self, card: &Card) -> bool {
+        if !self.can_return(card) {
+            info!("card return rejected {:?}", card);
+            return false;
+        }
+
+        let memory_cost = self.memory_cost(card).unwrap_or_default();
+        let memory_gain = self.memory_gain(card);
+
+        for (player, mut memory_cache, mut hand, mut board) in self.player_query.iter_mut() {
+            if player.0 == card.owner {
+                memory_cache.0 = memory_cache.0.saturating_sub(memory_gain).saturating_add(memory_cost).min(MEMORY_CACHE_MAX_VALUE);
+                if let Some(card) = board.remove(card.instance) {
+                    hand.0.push(card);
+                }
+            }
+        }
+
+        info!("card returned {:?}", card);
+        self.played_this_turn.0.retain(|instance| *instance != card.instance);
+        self.card_returned_events.send(CardReturnedEvent {
+            player: card.owner,
+            card: card.clone(),
+        });
+
+        true
+    }
+}
+
+pub(super) fn refill_memory_cache(
+    current_player: Res<CurrentPlayerState>,
+    mut player_query: Query<(&Player, &mut MemoryCache)>,
+) {
+    for (player, mut memory_cache) in player_query.iter_mut() {
+        if player.0 == current_player.0 {
+            memory_cache.0 = MEMORY_CACHE_INITIAL_VALUE;
+        }
+    }
+}
+
+pub(super) fn reset_played_this_turn(mut played_this_turn: ResMut<PlayedThisTurn>) {
+    played_this_turn.0.clear();
+}
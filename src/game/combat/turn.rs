@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+
+use super::{AiController, HasPriority, Player, PlayerNumber, TurnState};
+
+#[derive(Resource, Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct CurrentPlayerState(pub PlayerNumber);
+
+#[allow(dead_code)]
+pub struct TurnStarted(pub PlayerNumber);
+
+#[allow(dead_code)]
+pub struct TurnEnded(pub PlayerNumber);
+
+#[allow(dead_code)]
+pub struct PhaseChanged {
+    pub player: PlayerNumber,
+    pub phase: TurnState,
+}
+
+pub struct EndTurnRequested(pub PlayerNumber);
+
+impl PlayerNumber {
+    pub fn opponent(&self) -> PlayerNumber {
+        match self {
+            PlayerNumber::One => PlayerNumber::Two,
+            PlayerNumber::Two => PlayerNumber::One,
+        }
+    }
+}
+
+impl TurnState {
+    pub fn next(&self) -> TurnState {
+        match self {
+            TurnState::DrawCards => TurnState::PlayCards,
+            TurnState::PlayCards => TurnState::ResolveEffects,
+            TurnState::ResolveEffects => TurnState::ResolveSideEffects,
+            TurnState::ResolveSideEffects => TurnState::EndTurn,
+            TurnState::EndTurn => TurnState::DrawCards,
+        }
+    }
+}
+
+pub(super) fn resolving_effects(state: Res<State<TurnState>>) -> bool {
+    matches!(state.0, TurnState::ResolveEffects | TurnState::ResolveSideEffects)
+}
+
+pub(super) fn start_turn(
+    current_player: Res<CurrentPlayerState>,
+    mut turn_started_events: EventWriter<TurnStarted>,
+) {
+    info!("turn started {:?}", current_player.0);
+    turn_started_events.send(TurnStarted(current_player.0));
+}
+
+pub(super) fn send_phase_changed(
+    state: Res<State<TurnState>>,
+    current_player: Res<CurrentPlayerState>,
+    mut phase_changed_events: EventWriter<PhaseChanged>,
+) {
+    info!("phase changed {:?} {:?}", current_player.0, state.0);
+    phase_changed_events.send(PhaseChanged {
+        player: current_player.0,
+        phase: state.0,
+    });
+}
+
+pub(super) fn advance_turn_state(
+    state: Res<State<TurnState>>,
+    mut next_state: ResMut<NextState<TurnState>>,
+) {
+    next_state.set(state.0.next());
+}
+
+pub(super) fn request_end_turn_on_key(
+    keyboard_input: Res<Input<KeyCode>>,
+    current_player: Res<CurrentPlayerState>,
+    ai_query: Query<&Player, With<AiController>>,
+    mut end_turn_requested_events: EventWriter<EndTurnRequested>,
+) {
+    let ai_controlled = ai_query.iter().any(|player| player.0 == current_player.0);
+
+    if !ai_controlled && keyboard_input.just_pressed(KeyCode::Space) {
+        end_turn_requested_events.send(EndTurnRequested(current_player.0));
+    }
+}
+
+pub(super) fn end_play_cards(
+    mut end_turn_requested_events: EventReader<EndTurnRequested>,
+    current_player: Res<CurrentPlayerState>,
+    mut next_state: ResMut<NextState<TurnState>>,
+) {
+    for event in end_turn_requested_events.iter() {
+        if event.0 == current_player.0 {
+            next_state.set(TurnState::PlayCards.next());
+        }
+    }
+}
+
+pub(super) fn pass_priority(
+    mut commands: Commands,
+    mut current_player: ResMut<CurrentPlayerState>,
+    player_query: Query<(Entity, &Player)>,
+    mut turn_ended_events: EventWriter<TurnEnded>,
+) {
+    let ended = current_player.0;
+    current_player.0 = ended.opponent();
+
+    for (entity, player) in player_query.iter() {
+        if player.0 == current_player.0 {
+            commands.entity(entity).insert(HasPriority);
+        } else {
+            commands.entity(entity).remove::<HasPriority>();
+        }
+    }
+
+    info!("priority passed to {:?}", current_player.0);
+    turn_ended_events.send(TurnEnded(ended));
+}
+
+pub(super) fn reset_turn(
+    mut current_player: ResMut<CurrentPlayerState>,
+    mut next_state: ResMut<NextState<TurnState>>,
+) {
+    *current_player = CurrentPlayerState::default();
+    next_state.set(TurnState::DrawCards);
+}
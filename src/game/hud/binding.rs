@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+use crate::game::combat::{MemoryCache, Player, PlayerNumber, SystemIntegrity, SystemProtection};
+
+use super::bitmap_font::BitmapNumberValue;
+
+pub trait PlayerStat: Component {
+    fn get_value(&self) -> u8;
+}
+
+impl PlayerStat for SystemIntegrity {
+    fn get_value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl PlayerStat for MemoryCache {
+    fn get_value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl PlayerStat for SystemProtection {
+    fn get_value(&self) -> u8 {
+        self.0
+    }
+}
+
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct HudOwner(pub PlayerNumber);
+
+pub(super) fn bind_player_stat<Stat: PlayerStat, Value: BitmapNumberValue>(
+    player_query: Query<(&Player, &Stat)>,
+    mut value_query: Query<(&HudOwner, &mut Value)>,
+) {
+    for (player, stat) in player_query.iter() {
+        for (owner, mut value) in value_query.iter_mut() {
+            if owner.0 == player.0 && value.get_value() != stat.get_value() {
+                value.set_value(stat.get_value());
+            }
+        }
+    }
+}
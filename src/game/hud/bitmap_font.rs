@@ -2,6 +2,8 @@ use bevy::prelude::*;
 
 pub trait BitmapNumberValue: Component {
     fn get_value(&self) -> u8;
+
+    fn set_value(&mut self, value: u8);
 }
 
 #[derive(Component)]
@@ -28,24 +30,25 @@ impl BitmapNumberConfig {
     }
 }
 
-pub(super) fn update_bitmap_number_digits<Value: BitmapNumberValue, Digit: Component>(
-    value_query: Query<(&Value, &BitmapNumberConfig), With<Value>>,
+pub fn update_bitmap_number_digits<Value: BitmapNumberValue, Digit: Component>(
+    value_query: Query<(&Value, &BitmapNumberConfig, &Children)>,
     mut digit_query: Query<(&mut Transform, &mut TextureAtlasSprite, &mut Visibility), With<Digit>>,
 ) {
-    let (value, config) = value_query.get_single().unwrap();
-    let binding = value.get_value().to_string();
-    let mut digits = binding.chars();
-    let count = digits.clone().count();
-
-    digit_query.iter_mut().enumerate().for_each(|(i, item)| {
-        let (mut transform, mut sprite, mut visibility) = item;
-
-        if let Some(digit) = digits.next() {
-            transform.translation.x = config.translation_x(count, i);
-            sprite.index = config.sprite_index(digit);
-            *visibility = Visibility::Visible;
-        } else {
-            *visibility = Visibility::Hidden;
-        }
-    });
-}
\ No newline at end of file
+    for (value, config, children) in value_query.iter() {
+        let binding = value.get_value().to_string();
+        let mut digits = binding.chars();
+        let count = digits.clone().count();
+
+        children.iter().enumerate().for_each(|(i, child)| {
+            if let Ok((mut transform, mut sprite, mut visibility)) = digit_query.get_mut(*child) {
+                if let Some(digit) = digits.next() {
+                    transform.translation.x = config.translation_x(count, i);
+                    sprite.index = config.sprite_index(digit);
+                    *visibility = Visibility::Visible;
+                } else {
+                    *visibility = Visibility::Hidden;
+                }
+            }
+        });
+    }
+}
@@ -1,6 +1,10 @@
 use bevy::prelude::*;
 
+use crate::game::combat::PlayerNumber;
+
+use super::binding::HudOwner;
 use super::bitmap_font::{BitmapNumberConfig, BitmapNumberValue};
+use super::hud_mirror;
 
 #[derive(Component)]
 pub struct MemoryCache;
@@ -15,14 +19,17 @@ impl BitmapNumberValue for MemoryCacheValue {
     fn get_value(&self) -> u8 {
         self.0
     }
+
+    fn set_value(&mut self, value: u8) {
+        self.0 = value;
+    }
 }
 
 #[derive(Component)]
 pub struct MemoryCacheDigit;
 
-const MEMORY_CACHE_INITIAL_VALUE: u8 = 9;
-
 pub(super) fn spawn_memory_cache(
+    owner: PlayerNumber,
     texture_atlases: &mut ResMut<Assets<TextureAtlas>>,
     asset_server: &Res<AssetServer>,
     parent: &mut ChildBuilder,
@@ -30,16 +37,17 @@ pub(super) fn spawn_memory_cache(
     parent.spawn((
         MemoryCache {},
         SpatialBundle {
-            transform: Transform::from_xyz(39.0, -39.0, 0.0),
+            transform: Transform::from_xyz(39.0 * hud_mirror(owner), -39.0, 0.0),
             ..default()
         },
     )).with_children(|parent| {
-        spawn_memory_cache_value(texture_atlases, asset_server, parent);
-        spawn_memory_cache_background(asset_server, parent);
+        spawn_memory_cache_value(owner, texture_atlases, asset_server, parent);
+        spawn_memory_cache_background(owner, asset_server, parent);
     });
 }
 
 fn spawn_memory_cache_value(
+    owner: PlayerNumber,
     texture_atlases: &mut ResMut<Assets<TextureAtlas>>,
     asset_server: &Res<AssetServer>,
     parent: &mut ChildBuilder,
@@ -58,7 +66,8 @@ fn spawn_memory_cache_value(
     let texture_atlas_handle = &texture_atlases.add(texture_atlas);
 
     parent.spawn((
-        MemoryCacheValue(MEMORY_CACHE_INITIAL_VALUE),
+        MemoryCacheValue(0),
+        HudOwner(owner),
         BitmapNumberConfig {
             tile_size,
             columns,
@@ -66,7 +75,7 @@ fn spawn_memory_cache_value(
             font_spacing: 1.0,
         },
         SpatialBundle {
-            transform: Transform::from_xyz(6.0, 2.0, 0.0),
+            transform: Transform::from_xyz(6.0 * hud_mirror(owner), 2.0, 0.0),
             ..default()
         },
     )).with_children(|parent| {
@@ -84,12 +93,17 @@ fn spawn_memory_cache_value(
 
 
 fn spawn_memory_cache_background(
+    owner: PlayerNumber,
     asset_server: &Res<AssetServer>,
     parent: &mut ChildBuilder,
 ) {
     parent.spawn((
         MemoryCacheBackground {},
         SpriteBundle {
+            sprite: Sprite {
+                flip_x: hud_mirror(owner) < 0.0,
+                ..default()
+            },
             texture: asset_server.load("textures/UI/MB/UI_MB_BG.png"),
             ..default()
         },
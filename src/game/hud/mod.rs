@@ -1,7 +1,14 @@
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
 
-use bitmap_font::*;
+use crate::game::{GameState, MatchEntity};
+use crate::game::layout::ScreenAnchor;
+use crate::game::combat::{
+    MemoryCache as PlayerMemoryCache, PlayerNumber, SystemIntegrity as PlayerSystemIntegrity,
+    SystemProtection as PlayerSystemProtection,
+};
+
+use binding::*;
+pub use bitmap_font::*;
 use memory_cache::*;
 use system_integrity::*;
 use system_protection::*;
@@ -10,12 +17,16 @@ mod system_integrity;
 mod memory_cache;
 mod system_protection;
 mod bitmap_font;
+mod binding;
 
 #[derive(Component)]
 pub struct Hud;
 
+const HUD_PLAYERS: [PlayerNumber; 2] = [PlayerNumber::One, PlayerNumber::Two];
+
 pub struct HudPlugin;
 
+#[allow(dead_code)]
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub struct HudSpawnSystemSet;
 
@@ -24,42 +35,67 @@ const HUD_MARGIN: f32 = 16.0;
 impl Plugin for HudPlugin {
     fn build(&self, app: &mut App) {
         app
-            .add_state::<SystemIntegrityState>()
             .register_type::<SystemIntegrityValue>()
             .register_type::<MemoryCacheValue>()
             .register_type::<SystemProtectionValue>()
-            .add_startup_system(spawn_hud)
-            .add_system(update_system_integrity_state)
+            .register_type::<HudOwner>()
+            .add_system(spawn_hud.in_schedule(OnEnter(GameState::InMatch)))
+            .add_system(bind_player_stat::<PlayerSystemIntegrity, SystemIntegrityValue>)
+            .add_system(bind_player_stat::<PlayerMemoryCache, MemoryCacheValue>)
+            .add_system(bind_player_stat::<PlayerSystemProtection, SystemProtectionValue>)
+            .add_system(update_system_integrity_state.after(bind_player_stat::<PlayerSystemIntegrity, SystemIntegrityValue>))
             .add_system(update_system_integrity_animation.after(update_system_integrity_state))
             .add_system(update_system_integrity_color.after(update_system_integrity_state))
-            .add_system(update_bitmap_number_digits::<SystemIntegrityValue, SystemIntegrityDigit>)
-            .add_system(update_bitmap_number_digits::<MemoryCacheValue, MemoryCacheDigit>)
-            .add_system(update_bitmap_number_digits::<SystemProtectionValue, SystemProtectionDigit>);
+            .add_system(update_bitmap_number_digits::<SystemIntegrityValue, SystemIntegrityDigit>
+                .after(bind_player_stat::<PlayerSystemIntegrity, SystemIntegrityValue>))
+            .add_system(update_bitmap_number_digits::<MemoryCacheValue, MemoryCacheDigit>
+                .after(bind_player_stat::<PlayerMemoryCache, MemoryCacheValue>))
+            .add_system(update_bitmap_number_digits::<SystemProtectionValue, SystemProtectionDigit>
+                .after(bind_player_stat::<PlayerSystemProtection, SystemProtectionValue>));
+    }
+}
+
+fn hud_mirror(owner: PlayerNumber) -> f32 {
+    match owner {
+        PlayerNumber::One => 1.0,
+        PlayerNumber::Two => -1.0,
+    }
+}
+
+fn hud_anchor(owner: PlayerNumber) -> ScreenAnchor {
+    match owner {
+        PlayerNumber::One => ScreenAnchor::new(
+            ScreenAnchor::BOTTOM_LEFT,
+            Vec2::new(58.0 + HUD_MARGIN, 162.0 + HUD_MARGIN),
+        ),
+        PlayerNumber::Two => ScreenAnchor::new(
+            ScreenAnchor::TOP_RIGHT,
+            Vec2::new(-58.0 - HUD_MARGIN, -84.0 - HUD_MARGIN),
+        ),
     }
 }
 
 fn spawn_hud(
     mut commands: Commands,
     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
     asset_server: Res<AssetServer>,
 ) {
-    let window = window_query.get_single().unwrap();
-    let parent = commands.spawn((
-        Hud {},
-        SpatialBundle {
-            transform: Transform::from_xyz(
-                -window.width() / 2.0 + 58.0 + HUD_MARGIN,
-                -window.height() / 2.0 + 162.0 + HUD_MARGIN,
-                0.0,
-            ).with_scale(Vec3::splat(2.0)),
-            ..default()
-        }
-    )).id();
+    for owner in HUD_PLAYERS {
+        let parent = commands.spawn((
+            MatchEntity,
+            Hud {},
+            HudOwner(owner),
+            hud_anchor(owner),
+            SpatialBundle {
+                transform: Transform::from_scale(Vec3::splat(2.0)),
+                ..default()
+            }
+        )).id();
 
-    commands.entity(parent).with_children(|parent| {
-        spawn_system_integrity(&mut texture_atlases, &asset_server, parent);
-        spawn_memory_cache(&mut texture_atlases, &asset_server, parent);
-        spawn_system_protection(&mut texture_atlases, &asset_server, parent);
-    });
+        commands.entity(parent).with_children(|parent| {
+            spawn_system_integrity(owner, &mut texture_atlases, &asset_server, parent);
+            spawn_memory_cache(owner, &mut texture_atlases, &asset_server, parent);
+            spawn_system_protection(owner, &mut texture_atlases, &asset_server, parent);
+        });
+    }
 }
@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 
 use crate::game::animation::{AnimationIndices, AnimationTimer};
+use crate::game::combat::PlayerNumber;
 
+use super::binding::HudOwner;
 use super::bitmap_font::{BitmapNumberConfig, BitmapNumberValue};
 
 #[derive(Component)]
@@ -20,12 +22,16 @@ impl BitmapNumberValue for SystemIntegrityValue {
     fn get_value(&self) -> u8 {
         self.0
     }
+
+    fn set_value(&mut self, value: u8) {
+        self.0 = value;
+    }
 }
 
 #[derive(Component, Reflect)]
 pub struct SystemIntegrityDigit;
 
-#[derive(States, Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[derive(Component, Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
 pub(super) enum SystemIntegrityState {
     #[default]
     Absolute,
@@ -34,24 +40,25 @@ pub(super) enum SystemIntegrityState {
     Hacked,
 }
 
-const SYSTEM_INTEGRITY_INITIAL_VALUE: u8 = 100;
-
 pub(super) fn spawn_system_integrity(
+    owner: PlayerNumber,
     texture_atlases: &mut ResMut<Assets<TextureAtlas>>,
     asset_server: &Res<AssetServer>,
     parent: &mut ChildBuilder,
 ) {
     parent.spawn((
         SystemIntegrity {},
+        SystemIntegrityState::default(),
         SpatialBundle { ..default() },
     )).with_children(|parent| {
-        spawn_system_integrity_value(texture_atlases, asset_server, parent);
+        spawn_system_integrity_value(owner, texture_atlases, asset_server, parent);
         spawn_system_integrity_background(texture_atlases, asset_server, parent);
         spawn_system_integrity_fan(texture_atlases, asset_server, parent);
     });
 }
 
 fn spawn_system_integrity_value(
+    owner: PlayerNumber,
     texture_atlases: &mut ResMut<Assets<TextureAtlas>>,
     asset_server: &Res<AssetServer>,
     parent: &mut ChildBuilder,
@@ -70,7 +77,8 @@ fn spawn_system_integrity_value(
     let texture_atlas_handle = &texture_atlases.add(texture_atlas);
 
     parent.spawn((
-        SystemIntegrityValue(SYSTEM_INTEGRITY_INITIAL_VALUE),
+        SystemIntegrityValue(0),
+        HudOwner(owner),
         BitmapNumberConfig {
             tile_size,
             columns,
@@ -144,82 +152,88 @@ fn spawn_system_integrity_fan(
     ));
 }
 
+#[allow(clippy::collapsible_match)]
 pub(super) fn update_system_integrity_state(
-    state: Res<State<SystemIntegrityState>>,
-    mut next_state: ResMut<NextState<SystemIntegrityState>>,
-    mut query: Query<&SystemIntegrityValue>,
+    value_query: Query<(&SystemIntegrityValue, &Parent), Changed<SystemIntegrityValue>>,
+    mut state_query: Query<&mut SystemIntegrityState>,
 ) {
-    if let Ok(system_integrity) = query.get_single_mut() {
-        match system_integrity.0 {
-            51..=100 => {
-                if state.0 != SystemIntegrityState::Absolute {
-                    next_state.set(SystemIntegrityState::Absolute);
+    for (system_integrity, parent) in value_query.iter() {
+        if let Ok(mut state) = state_query.get_mut(parent.get()) {
+            match system_integrity.0 {
+                51..=100 => {
+                    if *state != SystemIntegrityState::Absolute {
+                        *state = SystemIntegrityState::Absolute;
+                    }
                 }
-            }
-            26..=50 => {
-                if state.0 != SystemIntegrityState::Reliable {
-                    next_state.set(SystemIntegrityState::Reliable);
+                26..=50 => {
+                    if *state != SystemIntegrityState::Reliable {
+                        *state = SystemIntegrityState::Reliable;
+                    }
                 }
-            }
-            1..=25 => {
-                if state.0 != SystemIntegrityState::Vulnerable {
-                    next_state.set(SystemIntegrityState::Vulnerable);
+                1..=25 => {
+                    if *state != SystemIntegrityState::Vulnerable {
+                        *state = SystemIntegrityState::Vulnerable;
+                    }
                 }
-            }
-            0 => {
-                if state.0 != SystemIntegrityState::Hacked {
-                    next_state.set(SystemIntegrityState::Hacked);
+                0 => {
+                    if *state != SystemIntegrityState::Hacked {
+                        *state = SystemIntegrityState::Hacked;
+                    }
                 }
+                _ => ()
             }
-            _ => ()
         }
     }
 }
 
 pub(super) fn update_system_integrity_color(
-    state: Res<State<SystemIntegrityState>>,
+    state_query: Query<(&SystemIntegrityState, &Children), Changed<SystemIntegrityState>>,
     mut background_query: Query<&mut TextureAtlasSprite, With<SystemIntegrityBackground>>,
     mut value_query: Query<&mut BitmapNumberConfig, With<SystemIntegrityValue>>,
 ) {
-    if state.is_changed() {
-        let color_index = match state.0 {
+    for (state, children) in state_query.iter() {
+        let color_index = match state {
             SystemIntegrityState::Absolute => 0,
             SystemIntegrityState::Reliable => 1,
             SystemIntegrityState::Vulnerable | SystemIntegrityState::Hacked => 2
         };
 
-        if let Ok(mut sprite) = background_query.get_single_mut() {
-            sprite.index = color_index;
-        }
+        for child in children.iter() {
+            if let Ok(mut sprite) = background_query.get_mut(*child) {
+                sprite.index = color_index;
+            }
 
-        if let Ok(mut config) = value_query.get_single_mut() {
-            config.row = color_index;
+            if let Ok(mut config) = value_query.get_mut(*child) {
+                config.row = color_index;
+            }
         }
     }
 }
 
 pub(super) fn update_system_integrity_animation(
-    state: Res<State<SystemIntegrityState>>,
-    mut query: Query<&mut AnimationIndices, With<SystemIntegrityFan>>,
+    state_query: Query<(&SystemIntegrityState, &Children), Changed<SystemIntegrityState>>,
+    mut fan_query: Query<&mut AnimationIndices, With<SystemIntegrityFan>>,
 ) {
-    if state.is_changed() {
-        if let Ok(mut animation_indices) = query.get_single_mut() {
-            match state.0 {
-                SystemIntegrityState::Absolute => {
-                    animation_indices.first = 0;
-                    animation_indices.last = 2;
-                }
-                SystemIntegrityState::Reliable => {
-                    animation_indices.first = 3;
-                    animation_indices.last = 5;
-                }
-                SystemIntegrityState::Vulnerable => {
-                    animation_indices.first = 6;
-                    animation_indices.last = 12;
-                }
-                SystemIntegrityState::Hacked => {
-                    animation_indices.first = 12;
-                    animation_indices.last = 12;
+    for (state, children) in state_query.iter() {
+        for child in children.iter() {
+            if let Ok(mut animation_indices) = fan_query.get_mut(*child) {
+                match state {
+                    SystemIntegrityState::Absolute => {
+                        animation_indices.first = 0;
+                        animation_indices.last = 2;
+                    }
+                    SystemIntegrityState::Reliable => {
+                        animation_indices.first = 3;
+                        animation_indices.last = 5;
+                    }
+                    SystemIntegrityState::Vulnerable => {
+                        animation_indices.first = 6;
+                        animation_indices.last = 12;
+                    }
+                    SystemIntegrityState::Hacked => {
+                        animation_indices.first = 12;
+                        animation_indices.last = 12;
+                    }
                 }
             }
         }
@@ -1,6 +1,10 @@
 use bevy::prelude::*;
 
+use crate::game::combat::PlayerNumber;
+
+use super::binding::HudOwner;
 use super::bitmap_font::{BitmapNumberConfig, BitmapNumberValue};
+use super::hud_mirror;
 
 #[derive(Component)]
 pub struct SystemProtection;
@@ -15,14 +19,17 @@ impl BitmapNumberValue for SystemProtectionValue {
     fn get_value(&self) -> u8 {
         self.0
     }
+
+    fn set_value(&mut self, value: u8) {
+        self.0 = value;
+    }
 }
 
 #[derive(Component)]
 pub struct SystemProtectionDigit;
 
-const SYSTEM_PROTECTION_INITIAL_VALUE: u8 = 0;
-
 pub(super) fn spawn_system_protection(
+    owner: PlayerNumber,
     texture_atlases: &mut ResMut<Assets<TextureAtlas>>,
     asset_server: &Res<AssetServer>,
     parent: &mut ChildBuilder,
@@ -30,16 +37,17 @@ pub(super) fn spawn_system_protection(
     parent.spawn((
         SystemProtection {},
         SpatialBundle {
-            transform: Transform::from_xyz(30.5, 22.5, 1.0),
+            transform: Transform::from_xyz(30.5 * hud_mirror(owner), 22.5, 1.0),
             ..default()
         },
     )).with_children(|parent| {
-        spawn_system_protection_value(texture_atlases, asset_server, parent);
-        spawn_system_protection_background(asset_server, parent);
+        spawn_system_protection_value(owner, texture_atlases, asset_server, parent);
+        spawn_system_protection_background(owner, asset_server, parent);
     });
 }
 
 fn spawn_system_protection_value(
+    owner: PlayerNumber,
     texture_atlases: &mut ResMut<Assets<TextureAtlas>>,
     asset_server: &Res<AssetServer>,
     parent: &mut ChildBuilder,
@@ -58,7 +66,8 @@ fn spawn_system_protection_value(
     let texture_atlas_handle = &texture_atlases.add(texture_atlas);
 
     parent.spawn((
-        SystemProtectionValue(SYSTEM_PROTECTION_INITIAL_VALUE),
+        SystemProtectionValue(0),
+        HudOwner(owner),
         BitmapNumberConfig {
             tile_size,
             columns,
@@ -66,7 +75,7 @@ fn spawn_system_protection_value(
             font_spacing: 1.0,
         },
         SpatialBundle {
-            transform: Transform::from_xyz(3.5, 2.5, 1.0),
+            transform: Transform::from_xyz(3.5 * hud_mirror(owner), 2.5, 1.0),
             ..default()
         },
     )).with_children(|parent| {
@@ -83,12 +92,17 @@ fn spawn_system_protection_value(
 }
 
 fn spawn_system_protection_background(
+    owner: PlayerNumber,
     asset_server: &Res<AssetServer>,
     parent: &mut ChildBuilder,
 ) {
     parent.spawn((
         SystemProtectionBackground {},
         SpriteBundle {
+            sprite: Sprite {
+                flip_x: hud_mirror(owner) < 0.0,
+                ..default()
+            },
             texture: asset_server.load("textures/UI/SO/UI_SO_BG.png"),
             ..default()
         },
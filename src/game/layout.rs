@@ -0,0 +1,71 @@
+use bevy::{
+    prelude::*,
+    transform::TransformSystem,
+    window::{PrimaryWindow, WindowResized},
+};
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ScreenAnchor {
+    pub anchor: Vec2,
+    pub offset: Vec2,
+}
+
+impl ScreenAnchor {
+    pub const CENTER: Vec2 = Vec2::ZERO;
+    pub const BOTTOM: Vec2 = Vec2::new(0.0, -0.5);
+    pub const BOTTOM_LEFT: Vec2 = Vec2::new(-0.5, -0.5);
+    pub const TOP_RIGHT: Vec2 = Vec2::new(0.5, 0.5);
+
+    pub fn new(anchor: Vec2, offset: Vec2) -> Self {
+        Self { anchor, offset }
+    }
+
+    pub fn translation(&self, window_size: Vec2) -> Vec2 {
+        self.anchor * window_size + self.offset
+    }
+}
+
+pub struct LayoutPlugin;
+
+impl Plugin for LayoutPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(anchor_to_window
+            .in_base_set(CoreSet::PostUpdate)
+            .before(TransformSystem::TransformPropagate));
+    }
+}
+
+pub fn window_size(window: &Window) -> Vec2 {
+    Vec2::new(window.width(), window.height())
+}
+
+pub fn cursor_to_world(
+    position: Vec2,
+    camera: Option<(&Camera, &GlobalTransform)>,
+    window: Option<&Window>,
+) -> Vec2 {
+    camera
+        .and_then(|(camera, camera_transform)| camera.viewport_to_world_2d(camera_transform, position))
+        .or_else(|| window.map(|window| position - window_size(window) / 2.0))
+        .unwrap_or(position)
+}
+
+fn anchor_to_window(
+    mut window_resized_events: EventReader<WindowResized>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut anchor_query: Query<(Ref<ScreenAnchor>, &mut Transform)>,
+) {
+    let resized = window_resized_events.iter().count() > 0;
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+    let size = window_size(window);
+
+    for (screen_anchor, mut transform) in anchor_query.iter_mut() {
+        if resized || screen_anchor.is_changed() {
+            let translation = screen_anchor.translation(size);
+            transform.translation.x = translation.x;
+            transform.translation.y = translation.y;
+        }
+    }
+}
@@ -1,27 +1,77 @@
 use bevy::prelude::*;
 
 use crate::game::animation::AnimationPlugin;
-use crate::game::card::CardPlugin;
-use crate::game::combat::CombatPlugin;
-use crate::game::hud::HudPlugin;
+use crate::game::card::{CardInputPlugin, CardPlugin, CardRenderPlugin};
+use crate::game::combat::{CombatInputPlugin, CombatPlugin};
+use crate::game::layout::LayoutPlugin;
+use crate::game::screen::ScreenPlugin;
+
+pub use hud::HudPlugin;
+pub use simulation::SimulationPlugin;
 
 mod hud;
 mod animation;
 mod card;
 mod combat;
+mod layout;
+mod screen;
+mod simulation;
+mod time_step;
+#[cfg(test)]
+mod tests;
+
+#[derive(States, Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum GameState {
+    #[default]
+    Menu,
+    InMatch,
+    GameOver,
+}
+
+#[derive(Component)]
+pub struct MatchEntity;
 
 pub struct GamePlugin;
 
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugin(AnimationPlugin)
-            .add_plugin(HudPlugin)
+        app.add_state::<GameState>()
+            .add_plugin(AnimationPlugin)
             .add_plugin(CardPlugin)
             .add_plugin(CombatPlugin)
+            .add_system(despawn_match_entities.in_schedule(OnExit(GameState::GameOver)));
+    }
+}
+
+pub struct GameRenderPlugin;
+
+impl Plugin for GameRenderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(LayoutPlugin)
+            .add_plugin(CardRenderPlugin)
+            .add_plugin(ScreenPlugin)
             .add_startup_system(setup);
     }
 }
 
+pub struct GameInputPlugin;
+
+impl Plugin for GameInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(CardInputPlugin)
+            .add_plugin(CombatInputPlugin);
+    }
+}
+
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2dBundle::default());
 }
+
+fn despawn_match_entities(
+    mut commands: Commands,
+    match_entity_query: Query<Entity, With<MatchEntity>>,
+) {
+    for entity in match_entity_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+
+use crate::game::GameState;
+use crate::game::combat::{MatchResult, PlayerNumber};
+
+pub const SCREEN_FONT_PATH: &str = "fonts/Hack-Regular.ttf";
+const SCREEN_BACKGROUND_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.75);
+const RESTART_BUTTON_COLOR: Color = Color::rgb(0.15, 0.15, 0.15);
+const RESTART_BUTTON_HOVERED_COLOR: Color = Color::rgb(0.3, 0.3, 0.3);
+
+#[derive(Component)]
+pub struct MenuScreen;
+
+#[derive(Component)]
+pub struct GameOverScreen;
+
+#[derive(Component)]
+pub struct RestartButton;
+
+pub struct ScreenPlugin;
+
+impl Plugin for ScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(spawn_menu_screen.in_schedule(OnEnter(GameState::Menu)))
+            .add_system(despawn_screen::<MenuScreen>.in_schedule(OnExit(GameState::Menu)))
+            .add_system(start_match.in_set(OnUpdate(GameState::Menu)))
+            .add_system(spawn_game_over_screen.in_schedule(OnEnter(GameState::GameOver)))
+            .add_system(despawn_screen::<GameOverScreen>.in_schedule(OnExit(GameState::GameOver)))
+            .add_system(restart_match.in_set(OnUpdate(GameState::GameOver)));
+    }
+}
+
+fn screen_bundle() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            gap: Size::height(Val::Px(24.0)),
+            ..default()
+        },
+        background_color: SCREEN_BACKGROUND_COLOR.into(),
+        z_index: ZIndex::Global(10),
+        ..default()
+    }
+}
+
+fn screen_text(asset_server: &Res<AssetServer>, value: &str, font_size: f32) -> TextBundle {
+    TextBundle::from_section(
+        value,
+        TextStyle {
+            font: asset_server.load(SCREEN_FONT_PATH),
+            font_size,
+            color: Color::WHITE,
+        },
+    )
+}
+
+fn spawn_menu_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.spawn((
+        MenuScreen {},
+        screen_bundle(),
+    )).with_children(|parent| {
+        parent.spawn(screen_text(&asset_server, "SYSTEM BREACH", 64.0));
+        parent.spawn(screen_text(&asset_server, "Press Enter to start", 24.0));
+    });
+}
+
+fn spawn_game_over_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    match_result: Option<Res<MatchResult>>,
+) {
+    let title = match match_result.map(|match_result| match_result.winner) {
+        Some(PlayerNumber::One) => "SYSTEM SECURED",
+        _ => "SYSTEM HACKED",
+    };
+
+    commands.spawn((
+        GameOverScreen {},
+        screen_bundle(),
+    )).with_children(|parent| {
+        parent.spawn(screen_text(&asset_server, title, 64.0));
+        parent.spawn((
+            RestartButton {},
+            ButtonBundle {
+                style: Style {
+                    padding: UiRect::all(Val::Px(16.0)),
+                    ..default()
+                },
+                background_color: RESTART_BUTTON_COLOR.into(),
+                ..default()
+            },
+        )).with_children(|parent| {
+            parent.spawn(screen_text(&asset_server, "Restart (R)", 24.0));
+        });
+    });
+}
+
+fn despawn_screen<Screen: Component>(
+    mut commands: Commands,
+    screen_query: Query<Entity, With<Screen>>,
+) {
+    for entity in screen_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn start_match(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Return) {
+        next_state.set(GameState::InMatch);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn restart_match(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), (With<RestartButton>, Changed<Interaction>)>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::R) {
+        next_state.set(GameState::InMatch);
+    }
+
+    for (interaction, mut background_color) in button_query.iter_mut() {
+        match interaction {
+            Interaction::Clicked => next_state.set(GameState::InMatch),
+            Interaction::Hovered => *background_color = RESTART_BUTTON_HOVERED_COLOR.into(),
+            Interaction::None => *background_color = RESTART_BUTTON_COLOR.into(),
+        }
+    }
+}
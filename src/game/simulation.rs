@@ -0,0 +1,136 @@
+use bevy::{
+    app::AppExit,
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::game::GameState;
+use crate::game::time_step::FixedTimeStepPlugin;
+use crate::game::combat::{
+    AiSettings, AiStrategyKind, CardPlayedEvent, DeckRng, MatchResult, PlayerNumber, TurnEnded,
+};
+
+pub const SIMULATION_FRAME_SECONDS: f32 = 1.0 / 60.0;
+pub const SIMULATION_TURN_LIMIT: u32 = 200;
+
+pub struct SimulationPlugin {
+    pub matches: u32,
+    pub seed: Option<u64>,
+    pub strategy_one: AiStrategyKind,
+    pub strategy_two: AiStrategyKind,
+}
+
+impl Default for SimulationPlugin {
+    fn default() -> Self {
+        Self {
+            matches: 1,
+            seed: None,
+            strategy_one: AiStrategyKind::Defensive,
+            strategy_two: AiStrategyKind::GreedyDamage,
+        }
+    }
+}
+
+impl Plugin for SimulationPlugin {
+    fn build(&self, app: &mut App) {
+        if let Some(seed) = self.seed {
+            app.insert_resource(DeckRng::seeded(seed));
+        }
+
+        app.add_plugin(FixedTimeStepPlugin { seconds: SIMULATION_FRAME_SECONDS })
+            .insert_resource(AiSettings {
+                think_delay: 0.0,
+                strategies: HashMap::from_iter([
+                    (PlayerNumber::One, self.strategy_one),
+                    (PlayerNumber::Two, self.strategy_two),
+                ]),
+            })
+            .insert_resource(SimulationStats {
+                matches: self.matches,
+                ..default()
+            })
+            .add_startup_system(start_simulated_match)
+            .add_system(track_simulated_match.in_set(OnUpdate(GameState::InMatch)))
+            .add_system(record_simulated_match.in_schedule(OnEnter(GameState::GameOver)));
+    }
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct SimulationStats {
+    pub matches: u32,
+    pub played: u32,
+    pub draws: u32,
+    pub wins: HashMap<PlayerNumber, u32>,
+    pub card_plays: HashMap<String, u32>,
+    pub card_wins: HashMap<String, u32>,
+    turns: u32,
+    match_plays: Vec<(PlayerNumber, String)>,
+}
+
+fn start_simulated_match(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::InMatch);
+}
+
+fn track_simulated_match(
+    mut stats: ResMut<SimulationStats>,
+    mut card_played_events: EventReader<CardPlayedEvent>,
+    mut turn_ended_events: EventReader<TurnEnded>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for event in card_played_events.iter() {
+        stats.match_plays.push((event.player, event.card.id.clone()));
+    }
+
+    stats.turns += turn_ended_events.iter().count() as u32;
+
+    if stats.turns >= SIMULATION_TURN_LIMIT {
+        info!("simulated match stalled after {:?} turns", stats.turns);
+        next_state.set(GameState::GameOver);
+    }
+}
+
+fn record_simulated_match(
+    mut commands: Commands,
+    mut stats: ResMut<SimulationStats>,
+    match_result: Option<Res<MatchResult>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut app_exit_events: EventWriter<AppExit>,
+) {
+    let winner = match_result.map(|match_result| match_result.winner);
+    commands.remove_resource::<MatchResult>();
+
+    let match_plays = std::mem::take(&mut stats.match_plays);
+    for (player, id) in match_plays {
+        *stats.card_plays.entry(id.clone()).or_default() += 1;
+
+        if Some(player) == winner {
+            *stats.card_wins.entry(id).or_default() += 1;
+        }
+    }
+
+    match winner {
+        Some(winner) => *stats.wins.entry(winner).or_default() += 1,
+        None => stats.draws += 1,
+    }
+
+    stats.played += 1;
+    stats.turns = 0;
+    info!("simulated match {:?}/{:?} winner {:?}", stats.played, stats.matches, winner);
+
+    if stats.played < stats.matches {
+        next_state.set(GameState::InMatch);
+        return;
+    }
+
+    info!("simulation wins {:?} draws {:?}", stats.wins, stats.draws);
+    let mut card_ids: Vec<&String> = stats.card_plays.keys().collect();
+    card_ids.sort();
+
+    for id in card_ids {
+        let plays = stats.card_plays[id];
+        let wins = stats.card_wins.get(id).copied().unwrap_or_default();
+        info!("card {} played {} won {} ({:.1}%)", id, plays, wins, wins as f32 * 100.0 / plays as f32);
+    }
+
+    app_exit_events.send(AppExit);
+}
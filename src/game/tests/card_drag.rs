@@ -0,0 +1,185 @@
+use bevy::prelude::*;
+
+use crate::game::card::{Battlefield, CARD_SIZE, CardPlaced};
+use crate::game::combat::{CurrentPlayerState, MEMORY_CACHE_MAX_VALUE, MemoryCache, PlayerNumber};
+use crate::game::layout::ScreenAnchor;
+
+use super::TestApp;
+
+fn place_cards(test_app: &mut TestApp, count: usize) -> Vec<Entity> {
+    for _ in 0..count {
+        test_app.player_mut::<MemoryCache>(PlayerNumber::One).0 = MEMORY_CACHE_MAX_VALUE;
+        let card = test_app.hand()[0];
+        let to = test_app.battlefield_position(PlayerNumber::One);
+        test_app.drag(card, to);
+    }
+
+    let battlefield = test_app.battlefield(PlayerNumber::One);
+    assert_eq!(battlefield.len(), count);
+    battlefield
+}
+
+#[test]
+fn dropping_a_hand_card_on_own_battlefield_places_it() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    test_app.player_mut::<MemoryCache>(PlayerNumber::One).0 = MEMORY_CACHE_MAX_VALUE;
+
+    let hand = test_app.hand();
+    let card = hand[0];
+    let memory_cost = test_app.definition(card).memory_cost;
+    let to = test_app.battlefield_position(PlayerNumber::One);
+    test_app.drag(card, to);
+
+    assert_eq!(test_app.battlefield(PlayerNumber::One), vec![card]);
+    assert_eq!(test_app.hand(), hand[1..].to_vec());
+    assert!(test_app.has::<CardPlaced>(card));
+    assert_eq!(test_app.memory(PlayerNumber::One), MEMORY_CACHE_MAX_VALUE - memory_cost);
+}
+
+#[test]
+fn dropping_a_hand_card_on_another_area_starts_from_the_release_point() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+
+    let card = test_app.hand()[0];
+    let from = test_app.position(card);
+    let to = test_app.battlefield_position(PlayerNumber::One);
+    test_app.move_cursor(from);
+    test_app.press();
+    test_app.update();
+    test_app.move_cursor(to);
+    test_app.update();
+    test_app.release();
+    test_app.update();
+    test_app.update();
+
+    assert!(test_app.has::<CardPlaced>(card));
+    assert!(test_app.position(card).distance(to) < CARD_SIZE.x / 2.0);
+}
+
+#[test]
+fn dropping_a_hand_card_on_opponent_battlefield_is_rejected() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    let memory = test_app.memory(PlayerNumber::One);
+
+    let hand = test_app.hand();
+    let to = test_app.battlefield_position(PlayerNumber::Two);
+    test_app.drag(hand[0], to);
+
+    assert_eq!(test_app.hand(), hand);
+    assert!(test_app.battlefield(PlayerNumber::Two).is_empty());
+    assert!(!test_app.has::<CardPlaced>(hand[0]));
+    assert_eq!(test_app.memory(PlayerNumber::One), memory);
+}
+
+#[test]
+fn dropping_a_hand_card_on_another_slot_reorders_the_hand() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+
+    let hand = test_app.hand();
+    assert!(hand.len() >= 3);
+    let to = test_app.position(hand[2]);
+    test_app.drag(hand[0], to);
+
+    let mut expected = hand.clone();
+    let card = expected.remove(0);
+    expected.insert(2, card);
+    assert_eq!(test_app.hand(), expected);
+}
+
+#[test]
+fn moving_a_battlefield_card_left_lands_on_the_hovered_slot() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    let placed = place_cards(&mut test_app, 3);
+
+    let to = test_app.position(placed[1]);
+    test_app.drag(placed[2], to);
+
+    assert_eq!(test_app.battlefield(PlayerNumber::One), vec![placed[0], placed[2], placed[1]]);
+}
+
+#[test]
+fn moving_a_battlefield_card_right_lands_on_the_hovered_slot() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    let placed = place_cards(&mut test_app, 3);
+
+    let to = test_app.position(placed[1]);
+    test_app.drag(placed[0], to);
+
+    assert_eq!(test_app.battlefield(PlayerNumber::One), vec![placed[1], placed[0], placed[2]]);
+}
+
+#[test]
+fn moving_a_card_on_an_off_center_battlefield_lands_on_the_hovered_slot() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    let mut anchor_query = test_app.app.world.query::<(&Battlefield, &mut ScreenAnchor)>();
+    for (battlefield, mut screen_anchor) in anchor_query.iter_mut(&mut test_app.app.world) {
+        if battlefield.0 == PlayerNumber::One {
+            screen_anchor.offset.x = 2.0 * CARD_SIZE.x;
+        }
+    }
+    test_app.settle();
+    let placed = place_cards(&mut test_app, 3);
+
+    let to = test_app.position(placed[1]);
+    test_app.drag(placed[2], to);
+
+    assert_eq!(test_app.battlefield(PlayerNumber::One), vec![placed[0], placed[2], placed[1]]);
+}
+
+#[test]
+fn dropping_a_battlefield_card_in_place_keeps_the_order() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    let placed = place_cards(&mut test_app, 3);
+
+    for card in placed.clone() {
+        let to = test_app.position(card);
+        test_app.drag(card, to);
+        assert_eq!(test_app.battlefield(PlayerNumber::One), placed);
+    }
+}
+
+#[test]
+fn moving_a_battlefield_card_outside_its_owner_turn_is_rejected() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    let placed = place_cards(&mut test_app, 2);
+
+    test_app.press_key(KeyCode::Space);
+    test_app.update_until(|world| world.resource::<CurrentPlayerState>().0 == PlayerNumber::Two);
+    test_app.settle();
+
+    let to = test_app.position(placed[1]);
+    test_app.drag(placed[0], to);
+
+    assert_eq!(test_app.battlefield(PlayerNumber::One), placed);
+}
+
+#[test]
+fn returning_a_memory_card_after_spending_its_gain_is_rejected() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+
+    let card = test_app.hand()[0];
+    test_app.replace_card(card, "overclock");
+    let memory = test_app.memory(PlayerNumber::One);
+    let to = test_app.battlefield_position(PlayerNumber::One);
+    test_app.drag(card, to);
+    assert_eq!(test_app.battlefield(PlayerNumber::One), vec![card]);
+    assert!(test_app.memory(PlayerNumber::One) > memory);
+
+    test_app.player_mut::<MemoryCache>(PlayerNumber::One).0 = 0;
+    let hand_card = test_app.hand()[0];
+    let to = test_app.position(hand_card);
+    test_app.drag(card, to);
+
+    assert_eq!(test_app.battlefield(PlayerNumber::One), vec![card]);
+    assert_eq!(test_app.memory(PlayerNumber::One), 0);
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+use crate::game::card::CardFocus;
+use crate::game::combat::{CurrentPlayerState, MEMORY_CACHE_MAX_VALUE, MemoryCache, PlayerNumber};
+
+use super::TestApp;
+
+#[test]
+fn keyboard_focus_places_a_hand_card_on_own_battlefield() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    test_app.player_mut::<MemoryCache>(PlayerNumber::One).0 = MEMORY_CACHE_MAX_VALUE;
+
+    let card = test_app.hand()[0];
+    for key_code in [KeyCode::Right, KeyCode::Return, KeyCode::Up, KeyCode::Return] {
+        test_app.press_key(key_code);
+        test_app.update();
+    }
+    test_app.settle();
+
+    assert_eq!(test_app.battlefield(PlayerNumber::One), vec![card]);
+}
+
+#[test]
+fn gamepad_focus_places_a_hand_card_on_own_battlefield() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    test_app.player_mut::<MemoryCache>(PlayerNumber::One).0 = MEMORY_CACHE_MAX_VALUE;
+
+    let card = test_app.hand()[0];
+    for button_type in [
+        GamepadButtonType::DPadRight,
+        GamepadButtonType::South,
+        GamepadButtonType::DPadUp,
+        GamepadButtonType::South,
+    ] {
+        test_app.press_button(button_type);
+        test_app.update();
+    }
+    test_app.settle();
+
+    assert_eq!(test_app.battlefield(PlayerNumber::One), vec![card]);
+}
+
+#[test]
+fn keyboard_focus_never_holds_an_opponent_card() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+
+    test_app.press_key(KeyCode::Space);
+    test_app.update_until(|world| world.resource::<CurrentPlayerState>().0 == PlayerNumber::Two);
+    test_app.update_until(|world| world.resource::<CurrentPlayerState>().0 == PlayerNumber::One);
+    test_app.settle();
+    assert!(!test_app.battlefield(PlayerNumber::Two).is_empty());
+
+    for key_code in [KeyCode::Right, KeyCode::Up, KeyCode::Up, KeyCode::Up, KeyCode::Return] {
+        test_app.press_key(key_code);
+        test_app.update();
+    }
+
+    let focus = test_app.app.world.resource::<CardFocus>();
+    let focused = focus.focused.expect("a hand card should be focused");
+    let held = focus.held.expect("the focused card should be held");
+    assert_eq!(test_app.card(focused).owner, PlayerNumber::One);
+    assert_eq!(test_app.card(held).owner, PlayerNumber::One);
+}
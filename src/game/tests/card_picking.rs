@@ -0,0 +1,115 @@
+use bevy::{
+    ecs::event::ManualEventReader,
+    input::touch::TouchPhase,
+    prelude::*,
+};
+
+use crate::game::card::{
+    CardFocus, CardHovered, CardPressEvent, Picking, TOUCH_DRAG_THRESHOLD, TOUCH_LONG_PRESS_SECONDS,
+};
+use crate::game::combat::PlayerNumber;
+
+use super::TestApp;
+
+fn pressed_cards(test_app: &TestApp, reader: &mut ManualEventReader<CardPressEvent>) -> Vec<Entity> {
+    reader.iter(test_app.app.world.resource::<Events<CardPressEvent>>())
+        .map(|event| event.entity)
+        .collect()
+}
+
+#[test]
+fn pressing_a_hand_card_sends_a_press_and_focuses_it() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    let mut reader = test_app.app.world.resource::<Events<CardPressEvent>>().get_reader();
+
+    let card = test_app.hand()[1];
+    let position = test_app.position(card);
+    test_app.move_cursor(position);
+    test_app.press();
+    test_app.update();
+
+    assert_eq!(pressed_cards(&test_app, &mut reader), vec![card]);
+    assert_eq!(test_app.app.world.resource::<CardFocus>().focused, Some(card));
+}
+
+#[test]
+fn touching_a_hand_card_sends_a_press_before_the_drag_threshold() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    let mut reader = test_app.app.world.resource::<Events<CardPressEvent>>().get_reader();
+
+    let card = test_app.hand()[0];
+    let position = test_app.position(card);
+    test_app.touch(0, TouchPhase::Started, position);
+    test_app.update();
+
+    assert_eq!(pressed_cards(&test_app, &mut reader), vec![card]);
+    assert_eq!(test_app.app.world.resource::<Picking>().dragged, None);
+
+    test_app.touch(0, TouchPhase::Moved, position + Vec2::Y * (TOUCH_DRAG_THRESHOLD + 1.0));
+    test_app.update();
+
+    assert!(pressed_cards(&test_app, &mut reader).is_empty());
+    assert_eq!(test_app.app.world.resource::<Picking>().dragged, Some(card));
+}
+
+#[test]
+fn a_second_simultaneous_touch_is_ignored() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    let mut reader = test_app.app.world.resource::<Events<CardPressEvent>>().get_reader();
+
+    let hand = test_app.hand();
+    let first = test_app.position(hand[0]);
+    let second = test_app.position(hand[1]);
+    let to = test_app.battlefield_position(PlayerNumber::One);
+
+    test_app.touch(0, TouchPhase::Started, first);
+    test_app.update();
+    assert_eq!(pressed_cards(&test_app, &mut reader), vec![hand[0]]);
+
+    test_app.touch(1, TouchPhase::Started, second);
+    test_app.update();
+    test_app.touch(1, TouchPhase::Moved, to);
+    test_app.update();
+
+    assert!(pressed_cards(&test_app, &mut reader).is_empty());
+    assert_eq!(test_app.app.world.resource::<Picking>().dragged, None);
+
+    test_app.touch(1, TouchPhase::Ended, to);
+    test_app.touch(0, TouchPhase::Moved, to);
+    test_app.update();
+    assert_eq!(test_app.app.world.resource::<Picking>().dragged, Some(hand[0]));
+
+    test_app.touch(0, TouchPhase::Ended, to);
+    test_app.settle();
+
+    assert_eq!(test_app.battlefield(PlayerNumber::One), vec![hand[0]]);
+    assert_eq!(test_app.hand(), hand[1..].to_vec());
+}
+
+#[test]
+fn a_long_press_previews_the_touched_card() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+
+    let card = test_app.hand()[0];
+    let position = test_app.position(card);
+    test_app.touch(0, TouchPhase::Started, position);
+    test_app.update();
+    let started = test_app.app.world.resource::<Time>().elapsed_seconds();
+    test_app.update();
+
+    assert!(!test_app.has::<CardHovered>(card));
+
+    test_app.update_until(|world| world.get::<CardHovered>(card).is_some());
+
+    let elapsed = test_app.app.world.resource::<Time>().elapsed_seconds() - started;
+    assert!(elapsed >= TOUCH_LONG_PRESS_SECONDS);
+    assert_eq!(test_app.app.world.resource::<Picking>().dragged, None);
+
+    test_app.touch(0, TouchPhase::Ended, position);
+    test_app.update_until(|world| world.get::<CardHovered>(card).is_none());
+    assert_eq!(test_app.hand()[0], card);
+}
@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+
+use crate::game::card::CardEffect;
+use crate::game::combat::{
+    AiSettings, CurrentPlayerState, Deck, Hand, MEMORY_CACHE_MAX_VALUE, MemoryCache, PlayerNumber,
+    SYSTEM_INTEGRITY_INITIAL_VALUE,
+};
+
+use super::TestApp;
+
+#[test]
+fn match_starts_with_an_opening_hand_and_full_integrity() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+
+    assert!(!test_app.hand().is_empty());
+    assert!(test_app.battlefield(PlayerNumber::One).is_empty());
+    assert!(test_app.battlefield(PlayerNumber::Two).is_empty());
+    assert_eq!(test_app.integrity(PlayerNumber::One), SYSTEM_INTEGRITY_INITIAL_VALUE);
+    assert_eq!(test_app.integrity(PlayerNumber::Two), SYSTEM_INTEGRITY_INITIAL_VALUE);
+}
+
+#[test]
+fn played_damage_card_hits_the_opponent_at_end_of_turn() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    test_app.player_mut::<MemoryCache>(PlayerNumber::One).0 = MEMORY_CACHE_MAX_VALUE;
+
+    let (card, damage) = test_app.hand().into_iter()
+        .find_map(|card| {
+            let damage: u8 = test_app.definition(card).effects.iter()
+                .map(|effect| match effect {
+                    CardEffect::Damage(amount) => *amount,
+                    _ => 0,
+                })
+                .sum();
+            (damage > 0).then_some((card, damage))
+        })
+        .expect("opening hand should hold a damage card");
+    let to = test_app.battlefield_position(PlayerNumber::One);
+    test_app.drag(card, to);
+    assert_eq!(test_app.battlefield(PlayerNumber::One), vec![card]);
+
+    test_app.press_key(KeyCode::Space);
+    test_app.update_until(|world| world.resource::<CurrentPlayerState>().0 == PlayerNumber::Two);
+
+    assert_eq!(test_app.integrity(PlayerNumber::Two), SYSTEM_INTEGRITY_INITIAL_VALUE - damage);
+    assert_eq!(test_app.integrity(PlayerNumber::One), SYSTEM_INTEGRITY_INITIAL_VALUE);
+}
+
+#[test]
+fn end_turn_key_passes_priority_back_without_an_ai_opponent() {
+    let mut test_app = TestApp::new();
+    test_app.app.world.resource_mut::<AiSettings>().strategies.clear();
+    test_app.start_match();
+
+    test_app.press_key(KeyCode::Space);
+    test_app.update_until(|world| world.resource::<CurrentPlayerState>().0 == PlayerNumber::Two);
+    test_app.settle();
+
+    test_app.press_key(KeyCode::Space);
+    test_app.update_until(|world| world.resource::<CurrentPlayerState>().0 == PlayerNumber::One);
+}
+
+#[test]
+fn destroy_exhausts_a_card_from_the_opponent_hand() {
+    let mut test_app = TestApp::new();
+    test_app.start_match();
+    test_app.player_mut::<MemoryCache>(PlayerNumber::One).0 = MEMORY_CACHE_MAX_VALUE;
+
+    let card = test_app.hand()[0];
+    test_app.replace_card(card, "logic_bomb");
+    let opponent_hand = test_app.player_mut::<Hand>(PlayerNumber::Two).0.clone();
+    let to = test_app.battlefield_position(PlayerNumber::One);
+    test_app.drag(card, to);
+
+    test_app.press_key(KeyCode::Space);
+    test_app.update_until(|world| world.resource::<CurrentPlayerState>().0 == PlayerNumber::Two);
+
+    let exhausted = test_app.player_mut::<Deck>(PlayerNumber::Two).exhaust_pile.clone();
+    assert_eq!(exhausted.len(), 1);
+    assert_eq!(exhausted[0].instance, opponent_hand.last().expect("opponent should hold cards").instance);
+    assert!(test_app.player_mut::<Hand>(PlayerNumber::Two).get(exhausted[0].instance).is_none());
+}
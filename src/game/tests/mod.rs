@@ -0,0 +1,273 @@
+use bevy::{
+    ecs::system::SystemState,
+    hierarchy::HierarchyPlugin,
+    input::{
+        ButtonState, InputPlugin,
+        gamepad::{GamepadButtonChangedEvent, GamepadConnection, GamepadConnectionEvent, GamepadEvent, GamepadInfo},
+        keyboard::KeyboardInput,
+        mouse::MouseButtonInput,
+        touch::{TouchInput, TouchPhase},
+    },
+    prelude::*,
+    transform::TransformPlugin,
+    window::{ExitCondition, PrimaryWindow},
+};
+
+use crate::game::{GameInputPlugin, GamePlugin, GameState};
+use crate::game::animation::{WithAnyTween, tweens_finished};
+use crate::game::card::{Battlefield, CardDefinition, CardLibrary, CardLibraryHandle, CardsOrder, PlayerHand};
+use crate::game::combat::{Card, DeckRng, Hand, MemoryCache, Player, PlayerNumber, SystemIntegrity, TurnState};
+use crate::game::layout::LayoutPlugin;
+use crate::game::time_step::FixedTimeStepPlugin;
+
+mod card_drag;
+mod card_focus;
+mod card_picking;
+mod combat;
+mod simulation;
+
+const TEST_FRAME_SECONDS: f32 = 1.0 / 60.0;
+const TEST_WINDOW_SIZE: Vec2 = Vec2::new(1280.0, 720.0);
+const TEST_SEED: u64 = 7;
+const TEST_GAMEPAD: Gamepad = Gamepad { id: 0 };
+const MAX_SETTLE_FRAMES: usize = 600;
+
+pub struct TestApp {
+    pub app: App,
+}
+
+impl TestApp {
+    pub fn new() -> Self {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(TransformPlugin)
+            .add_plugin(HierarchyPlugin)
+            .add_plugin(InputPlugin)
+            .add_plugin(WindowPlugin {
+                primary_window: Some(Window {
+                    resolution: TEST_WINDOW_SIZE.into(),
+                    ..default()
+                }),
+                exit_condition: ExitCondition::DontExit,
+                close_when_requested: false,
+            })
+            .add_plugin(LayoutPlugin)
+            .add_plugin(GamePlugin)
+            .add_plugin(GameInputPlugin)
+            .add_plugin(FixedTimeStepPlugin { seconds: TEST_FRAME_SECONDS })
+            .insert_resource(DeckRng::seeded(TEST_SEED));
+        app.update();
+        install_card_library(&mut app);
+
+        Self { app }
+    }
+
+    pub fn update(&mut self) {
+        self.app.update();
+    }
+
+    pub fn update_until(&mut self, mut condition: impl FnMut(&mut World) -> bool) {
+        for _ in 0..MAX_SETTLE_FRAMES {
+            if condition(&mut self.app.world) {
+                return;
+            }
+            self.app.update();
+        }
+
+        panic!("condition not met after {} frames", MAX_SETTLE_FRAMES);
+    }
+
+    pub fn settle(&mut self) {
+        self.update();
+        self.update_until(|world| {
+            let mut tween_state = SystemState::<Query<(), WithAnyTween>>::new(world);
+            tweens_finished(tween_state.get(world))
+        });
+    }
+
+    pub fn start_match(&mut self) {
+        self.app.world.resource_mut::<NextState<GameState>>().set(GameState::InMatch);
+        self.update_until(|world| {
+            world.resource::<State<TurnState>>().0 == TurnState::PlayCards
+                && !hand_order(world).is_empty()
+        });
+        self.settle();
+    }
+
+    pub fn move_cursor(&mut self, world_position: Vec2) {
+        let window = self.window();
+        self.app.world.send_event(CursorMoved {
+            window,
+            position: world_position + TEST_WINDOW_SIZE / 2.0,
+        });
+    }
+
+    pub fn leave_window(&mut self) {
+        let window = self.window();
+        self.app.world.send_event(CursorLeft { window });
+    }
+
+    pub fn press(&mut self) {
+        self.app.world.send_event(MouseButtonInput {
+            button: MouseButton::Left,
+            state: ButtonState::Pressed,
+        });
+    }
+
+    pub fn release(&mut self) {
+        self.app.world.send_event(MouseButtonInput {
+            button: MouseButton::Left,
+            state: ButtonState::Released,
+        });
+    }
+
+    pub fn touch(&mut self, id: u64, phase: TouchPhase, world_position: Vec2) {
+        self.app.world.send_event(TouchInput {
+            phase,
+            position: Vec2::new(world_position.x, -world_position.y) + TEST_WINDOW_SIZE / 2.0,
+            force: None,
+            id,
+        });
+    }
+
+    pub fn drag(&mut self, entity: Entity, to: Vec2) {
+        let from = self.position(entity);
+        self.move_cursor(from);
+        self.press();
+        self.update();
+        self.move_cursor(to);
+        self.update();
+        self.release();
+        self.leave_window();
+        self.settle();
+    }
+
+    pub fn press_key(&mut self, key_code: KeyCode) {
+        self.app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state: ButtonState::Pressed,
+        });
+        self.update();
+        self.app.world.send_event(KeyboardInput {
+            scan_code: 0,
+            key_code: Some(key_code),
+            state: ButtonState::Released,
+        });
+    }
+
+    pub fn press_button(&mut self, button_type: GamepadButtonType) {
+        if !self.app.world.resource::<Gamepads>().contains(TEST_GAMEPAD) {
+            self.app.world.send_event(GamepadEvent::Connection(GamepadConnectionEvent::new(
+                TEST_GAMEPAD,
+                GamepadConnection::Connected(GamepadInfo { name: "test".to_string() }),
+            )));
+            self.update();
+        }
+
+        self.app.world.send_event(GamepadEvent::Button(GamepadButtonChangedEvent::new(TEST_GAMEPAD, button_type, 1.0)));
+        self.update();
+        self.app.world.send_event(GamepadEvent::Button(GamepadButtonChangedEvent::new(TEST_GAMEPAD, button_type, 0.0)));
+    }
+
+    pub fn position(&mut self, entity: Entity) -> Vec2 {
+        self.app.world.get::<GlobalTransform>(entity)
+            .expect("entity should have a transform")
+            .translation()
+            .truncate()
+    }
+
+    pub fn hand(&mut self) -> Vec<Entity> {
+        hand_order(&mut self.app.world)
+    }
+
+    pub fn battlefield(&mut self, owner: PlayerNumber) -> Vec<Entity> {
+        self.app.world
+            .query::<(&Battlefield, &CardsOrder)>()
+            .iter(&self.app.world)
+            .find(|(battlefield, _)| battlefield.0 == owner)
+            .map(|(_, cards_order)| cards_order.0.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn battlefield_position(&mut self, owner: PlayerNumber) -> Vec2 {
+        let (transform, _) = self.app.world
+            .query::<(&GlobalTransform, &Battlefield)>()
+            .iter(&self.app.world)
+            .find(|(_, battlefield)| battlefield.0 == owner)
+            .expect("battlefield should be spawned");
+        transform.translation().truncate()
+    }
+
+    pub fn card(&self, entity: Entity) -> Card {
+        self.app.world.get::<Card>(entity).expect("entity should be a card").clone()
+    }
+
+    pub fn definition(&self, entity: Entity) -> CardDefinition {
+        let card = self.card(entity);
+        let handle = &self.app.world.resource::<CardLibraryHandle>().0;
+        self.app.world.resource::<Assets<CardLibrary>>()
+            .get(handle)
+            .and_then(|library| library.get(&card.id))
+            .expect("card should be defined")
+            .clone()
+    }
+
+    pub fn replace_card(&mut self, entity: Entity, id: &str) {
+        let mut card = self.app.world.get_mut::<Card>(entity).expect("entity should be a card");
+        card.id = id.to_string();
+        let card = card.clone();
+
+        let mut hand = self.player_mut::<Hand>(card.owner);
+        if let Some(hand_card) = hand.0.iter_mut().find(|hand_card| hand_card.instance == card.instance) {
+            *hand_card = card;
+        }
+    }
+
+    pub fn has<T: Component>(&self, entity: Entity) -> bool {
+        self.app.world.get::<T>(entity).is_some()
+    }
+
+    pub fn player_mut<T: Component>(&mut self, owner: PlayerNumber) -> Mut<'_, T> {
+        let entity = self.player(owner);
+        self.app.world.get_mut::<T>(entity).expect("player should have the component")
+    }
+
+    pub fn memory(&mut self, owner: PlayerNumber) -> u8 {
+        self.player_mut::<MemoryCache>(owner).0
+    }
+
+    pub fn integrity(&mut self, owner: PlayerNumber) -> u8 {
+        self.player_mut::<SystemIntegrity>(owner).0
+    }
+
+    fn window(&mut self) -> Entity {
+        self.app.world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(&self.app.world)
+    }
+
+    fn player(&mut self, owner: PlayerNumber) -> Entity {
+        self.app.world
+            .query::<(Entity, &Player)>()
+            .iter(&self.app.world)
+            .find(|(_, player)| player.0 == owner)
+            .map(|(entity, _)| entity)
+            .expect("player should be spawned")
+    }
+}
+
+pub fn install_card_library(app: &mut App) {
+    let library: CardLibrary = ron::de::from_str(include_str!("../../../assets/cards/base.cards.ron"))
+        .expect("card library should parse");
+    let handle = app.world.resource_mut::<Assets<CardLibrary>>().add(library);
+    app.insert_resource(CardLibraryHandle(handle));
+}
+
+fn hand_order(world: &mut World) -> Vec<Entity> {
+    world.query_filtered::<&CardsOrder, With<PlayerHand>>()
+        .get_single(world)
+        .map(|cards_order| cards_order.0.clone())
+        .unwrap_or_default()
+}
@@ -0,0 +1,69 @@
+use bevy::{
+    ecs::event::ManualEventReader,
+    prelude::*,
+};
+
+use crate::game::{GamePlugin, SimulationPlugin};
+use crate::game::combat::{CardPlayedEvent, MatchResult, PlayerNumber, TurnEnded};
+use crate::game::simulation::SimulationStats;
+
+use super::install_card_library;
+
+const MAX_SIMULATED_FRAMES: usize = 20_000;
+
+fn simulation_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(AssetPlugin::default())
+        .add_plugin(GamePlugin)
+        .add_plugin(SimulationPlugin { seed: Some(1), ..default() });
+    app.update();
+    install_card_library(&mut app);
+    app
+}
+
+#[test]
+fn seeded_headless_match_ends_with_a_winner() {
+    let mut app = simulation_app();
+
+    let mut winner = None;
+    for _ in 0..MAX_SIMULATED_FRAMES {
+        app.update();
+
+        if let Some(match_result) = app.world.get_resource::<MatchResult>() {
+            winner = Some(match_result.winner);
+        }
+
+        if app.world.resource::<SimulationStats>().played > 0 {
+            break;
+        }
+    }
+
+    let stats = app.world.resource::<SimulationStats>();
+    assert_eq!(stats.played, 1);
+    assert_eq!(stats.draws, 0);
+    assert_eq!(stats.wins.values().sum::<u32>(), 1);
+    assert!(winner.is_some_and(|winner| stats.wins.contains_key(&winner)));
+}
+
+#[test]
+fn first_simulated_turn_plays_from_the_opening_hand() {
+    let mut app = simulation_app();
+    let mut card_played_reader = ManualEventReader::<CardPlayedEvent>::default();
+    let mut turn_ended_reader = ManualEventReader::<TurnEnded>::default();
+
+    let mut plays = 0;
+    for _ in 0..MAX_SIMULATED_FRAMES {
+        app.update();
+
+        plays += card_played_reader.iter(app.world.resource::<Events<CardPlayedEvent>>())
+            .filter(|event| event.player == PlayerNumber::One)
+            .count();
+
+        if turn_ended_reader.iter(app.world.resource::<Events<TurnEnded>>()).next().is_some() {
+            break;
+        }
+    }
+
+    assert!(plays > 0);
+}
@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::*,
+    time::{TimeSystem, TimeUpdateStrategy},
+    utils::Instant,
+};
+
+pub struct FixedTimeStepPlugin {
+    pub seconds: f32,
+}
+
+impl Plugin for FixedTimeStepPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(FixedTimeStep(Duration::from_secs_f32(self.seconds)))
+            .insert_resource(TimeUpdateStrategy::ManualInstant(Instant::now()))
+            .add_system(advance_fixed_time_step.in_base_set(CoreSet::First).before(TimeSystem));
+    }
+}
+
+#[derive(Resource)]
+pub struct FixedTimeStep(pub Duration);
+
+fn advance_fixed_time_step(
+    fixed_time_step: Res<FixedTimeStep>,
+    mut time_update_strategy: ResMut<TimeUpdateStrategy>,
+) {
+    if let TimeUpdateStrategy::ManualInstant(instant) = *time_update_strategy {
+        *time_update_strategy = TimeUpdateStrategy::ManualInstant(instant + fixed_time_step.0);
+    }
+}
@@ -1,5 +1,6 @@
 use bevy::{
     // diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin},
+    log::LogPlugin,
     prelude::*,
 };
 use bevy_inspector_egui::{
@@ -7,22 +8,59 @@ use bevy_inspector_egui::{
     quick::WorldInspectorPlugin
 };
 
-use game::GamePlugin;
+use game::{GameInputPlugin, GamePlugin, GameRenderPlugin, HudPlugin, SimulationPlugin};
 
 mod game;
 mod stats_skills;
 
+const DEFAULT_SIMULATED_MATCHES: u32 = 100;
+
+fn arg_value<T: std::str::FromStr>(args: &[String], name: &str) -> Option<T> {
+    args.iter()
+        .position(|arg| arg == name)
+        .and_then(|index| args.get(index + 1))
+        .and_then(|value| value.parse().ok())
+}
+
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
+
+    if args.iter().any(|arg| arg == "--headless") {
+        let simulation_plugin = SimulationPlugin::default();
+        App::new()
+            .add_plugins(MinimalPlugins)
+            .add_plugin(LogPlugin::default())
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(GamePlugin)
+            .add_plugin(SimulationPlugin {
+                matches: arg_value(&args, "--matches").unwrap_or(DEFAULT_SIMULATED_MATCHES),
+                seed: arg_value(&args, "--seed"),
+                strategy_one: arg_value(&args, "--ai-one").unwrap_or(simulation_plugin.strategy_one),
+                strategy_two: arg_value(&args, "--ai-two").unwrap_or(simulation_plugin.strategy_two),
+            })
+            .run();
+        return;
+    }
+
     App::new()
-        .add_plugins(DefaultPlugins.set(ImagePlugin::default_nearest()))
+        .add_plugins(DefaultPlugins
+            .set(ImagePlugin::default_nearest())
+            .set(AssetPlugin {
+                watch_for_changes: true,
+                ..default()
+            }))
         // .add_plugin(LogDiagnosticsPlugin::default())
         // .add_plugin(FrameTimeDiagnosticsPlugin::default())
         .add_plugin(WorldInspectorPlugin::new())
         // .add_system(ui_example)
         .add_plugin(GamePlugin)
+        .add_plugin(GameRenderPlugin)
+        .add_plugin(HudPlugin)
+        .add_plugin(GameInputPlugin)
         .run();
 }
 
+#[allow(dead_code)]
 fn ui_example(mut contexts: EguiContexts) {
     egui::Window::new("Hello").show(contexts.ctx_mut(), |ui| {
         ui.label("world");